@@ -0,0 +1,91 @@
+//! A `TaggedVec` wrapper that only allows appending.
+
+use std::ops::Deref;
+
+use crate::{TaggedRange, TaggedVec};
+
+/// A [`TaggedVec`] that only allows appending values and modifying them in place.
+///
+/// Since values are never removed, inserted or reordered, every index issued by [`Self::push`] stays valid and refers to the same value forever.
+/// Read access to the underlying `TaggedVec` is available via [`Deref`].
+pub struct AppendOnlyTaggedVec<Index, Value> {
+    vec: TaggedVec<Index, Value>,
+}
+
+impl<Index, Value> AppendOnlyTaggedVec<Index, Value> {
+    /// Creates a new empty `AppendOnlyTaggedVec`.
+    pub fn new() -> Self {
+        Self {
+            vec: TaggedVec::new(),
+        }
+    }
+
+    /// Inserts the given value at the back of the vector, returning its index.
+    pub fn push(&mut self, value: Value) -> Index
+    where
+        Index: From<usize>,
+    {
+        self.vec.push(value)
+    }
+
+    /// Inserts the given values at the back of the vector, returning the range of their indices.
+    pub fn extend_with_range(
+        &mut self,
+        values: impl IntoIterator<Item = Value>,
+    ) -> TaggedRange<Index>
+    where
+        Index: From<usize>,
+    {
+        let start = self.vec.len();
+        self.vec.vec.extend(values);
+        TaggedRange::new(start.into(), self.vec.len().into())
+    }
+
+    /// Returns a mutable reference to the value at the given index, or `None` if the index is out of bounds.
+    pub fn get_mut(&mut self, index: Index) -> Option<&mut Value>
+    where
+        Index: Into<usize>,
+    {
+        self.vec.vec.get_mut(index.into())
+    }
+
+    /// Consumes the `AppendOnlyTaggedVec`, returning the underlying `TaggedVec`.
+    pub fn into_inner(self) -> TaggedVec<Index, Value> {
+        self.vec
+    }
+}
+
+impl<Index, Value> Default for AppendOnlyTaggedVec<Index, Value> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Index, Value> From<TaggedVec<Index, Value>> for AppendOnlyTaggedVec<Index, Value> {
+    fn from(vec: TaggedVec<Index, Value>) -> Self {
+        Self { vec }
+    }
+}
+
+impl<Index, Value> Deref for AppendOnlyTaggedVec<Index, Value> {
+    type Target = TaggedVec<Index, Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AppendOnlyTaggedVec;
+    use crate::TaggedRange;
+
+    #[test]
+    fn append_only() {
+        let mut vec = AppendOnlyTaggedVec::<usize, _>::new();
+        assert_eq!(vec.push('a'), 0);
+        assert_eq!(vec.extend_with_range(['b', 'c']), TaggedRange::new(1, 3));
+        *vec.get_mut(1).unwrap() = 'B';
+        assert_eq!(*vec, ['a', 'B', 'c']);
+    }
+}
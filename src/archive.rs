@@ -0,0 +1,197 @@
+//! A container file format storing multiple named `TaggedVec`s.
+//!
+//! An archive consists of a magic number, the vectors in the format of [`TaggedVec::write_binary_with_type_header`], a table of contents, and finally the offset of the table of contents.
+//! All integers are stored as native-endian `u64`.
+
+use std::{
+    collections::BTreeMap,
+    io::{Error, ErrorKind, Read, Seek, SeekFrom, Write},
+    mem,
+};
+
+use crate::TaggedVec;
+
+const MAGIC: &[u8; 8] = b"TVARCHIV";
+/// The type tag of all archive entries.
+///
+/// Entries are identified by their name, and only the size and alignment of the value type are checked when reading them, since type names are not stable across compiler versions.
+const TYPE_TAG: u64 = 0;
+
+/// Writes multiple named [`TaggedVec`]s into a single archive file.
+///
+/// The archive is only complete after calling [`Self::finish`].
+pub struct TaggedArchiveWriter<W: Write> {
+    writer: W,
+    offset: u64,
+    table_of_contents: BTreeMap<String, u64>,
+}
+
+/// Reads [`TaggedVec`]s by name from an archive written by a [`TaggedArchiveWriter`].
+pub struct TaggedArchiveReader<R: Read + Seek> {
+    reader: R,
+    table_of_contents: BTreeMap<String, u64>,
+}
+
+impl<W: Write> TaggedArchiveWriter<W> {
+    /// Starts a new archive in the given writer.
+    pub fn new(mut writer: W) -> std::io::Result<Self> {
+        writer.write_all(MAGIC)?;
+        Ok(Self {
+            writer,
+            offset: MAGIC.len() as u64,
+            table_of_contents: Default::default(),
+        })
+    }
+
+    /// Adds the given `TaggedVec` to the archive under the given name.
+    ///
+    /// Fails with [`ErrorKind::InvalidInput`] if the name is already in use.
+    pub fn add<Index, Value: Copy>(
+        &mut self,
+        name: &str,
+        vec: &TaggedVec<Index, Value>,
+    ) -> std::io::Result<()> {
+        if self.table_of_contents.contains_key(name) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("duplicate archive entry name {name:?}"),
+            ));
+        }
+
+        vec.write_binary_with_type_header(&mut self.writer, TYPE_TAG)?;
+        self.table_of_contents.insert(name.to_owned(), self.offset);
+        // Type header, length header and data.
+        self.offset += (3 * mem::size_of::<u64>()
+            + mem::size_of::<usize>()
+            + mem::size_of_val(vec.as_untagged_slice())) as u64;
+        Ok(())
+    }
+
+    /// Writes the table of contents and returns the underlying writer.
+    pub fn finish(mut self) -> std::io::Result<W> {
+        let table_of_contents_offset = self.offset;
+        write_u64(&mut self.writer, self.table_of_contents.len() as u64)?;
+        for (name, offset) in &self.table_of_contents {
+            write_u64(&mut self.writer, name.len() as u64)?;
+            self.writer.write_all(name.as_bytes())?;
+            write_u64(&mut self.writer, *offset)?;
+        }
+        write_u64(&mut self.writer, table_of_contents_offset)?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+impl<R: Read + Seek> TaggedArchiveReader<R> {
+    /// Opens an archive by reading its table of contents.
+    pub fn new(mut reader: R) -> std::io::Result<Self> {
+        let mut magic = [0; MAGIC.len()];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(Error::new(ErrorKind::InvalidData, "not a tagged archive"));
+        }
+
+        reader.seek(SeekFrom::End(-(mem::size_of::<u64>() as i64)))?;
+        let table_of_contents_offset = read_u64(&mut reader)?;
+        reader.seek(SeekFrom::Start(table_of_contents_offset))?;
+
+        let mut table_of_contents = BTreeMap::new();
+        for _ in 0..read_u64(&mut reader)? {
+            // Read the name without trusting its length for the allocation.
+            let name_len = read_u64(&mut reader)?;
+            let mut name = Vec::new();
+            if reader.by_ref().take(name_len).read_to_end(&mut name)? as u64 != name_len {
+                return Err(Error::from(ErrorKind::UnexpectedEof));
+            }
+            let name = String::from_utf8(name)
+                .map_err(|error| Error::new(ErrorKind::InvalidData, error))?;
+            table_of_contents.insert(name, read_u64(&mut reader)?);
+        }
+
+        Ok(Self {
+            reader,
+            table_of_contents,
+        })
+    }
+
+    /// Returns the names of all vectors in the archive, in sorted order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.table_of_contents.keys().map(String::as_str)
+    }
+
+    /// Returns `true` if the archive contains a vector with the given name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.table_of_contents.contains_key(name)
+    }
+
+    /// Reads the vector with the given name.
+    ///
+    /// Fails with [`ErrorKind::NotFound`] if there is no such vector, and with [`ErrorKind::InvalidData`] if it was stored with a value type of a different size or alignment.
+    pub fn read<Index, Value: Copy>(
+        &mut self,
+        name: &str,
+    ) -> std::io::Result<TaggedVec<Index, Value>> {
+        let offset = *self.table_of_contents.get(name).ok_or_else(|| {
+            Error::new(
+                ErrorKind::NotFound,
+                format!("no archive entry named {name:?}"),
+            )
+        })?;
+        self.reader.seek(SeekFrom::Start(offset))?;
+        TaggedVec::read_binary_with_type_header(&mut self.reader, TYPE_TAG)
+    }
+}
+
+fn write_u64(writer: &mut impl Write, value: u64) -> std::io::Result<()> {
+    writer.write_all(&value.to_ne_bytes())
+}
+
+fn read_u64(reader: &mut impl Read) -> std::io::Result<u64> {
+    let mut buffer = [0; mem::size_of::<u64>()];
+    reader.read_exact(&mut buffer)?;
+    Ok(u64::from_ne_bytes(buffer))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::{TaggedArchiveReader, TaggedArchiveWriter};
+    use crate::TaggedVec;
+
+    #[test]
+    fn archive_roundtrip() {
+        let a = TaggedVec::<usize, u8>::from(vec![1, 2, 3]);
+        let b = TaggedVec::<usize, f64>::from(vec![0.5]);
+
+        let mut writer = TaggedArchiveWriter::new(Vec::new()).unwrap();
+        writer.add("a", &a).unwrap();
+        writer.add("b", &b).unwrap();
+        assert!(writer.add("a", &b).is_err());
+        let bytes = writer.finish().unwrap();
+
+        let mut reader = TaggedArchiveReader::new(Cursor::new(bytes)).unwrap();
+        assert_eq!(reader.names().collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(reader.read::<usize, f64>("b").unwrap(), b);
+        assert_eq!(reader.read::<usize, u8>("a").unwrap(), a);
+        assert!(reader.read::<usize, u16>("a").is_err());
+        assert!(reader.read::<usize, u8>("c").is_err());
+        assert_eq!(reader.read::<usize, i8>("a").unwrap(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn archive_corrupt_name_length() {
+        let mut writer = TaggedArchiveWriter::new(Vec::new()).unwrap();
+        writer
+            .add("a", &TaggedVec::<usize, u8>::from(vec![1]))
+            .unwrap();
+        let mut bytes = writer.finish().unwrap();
+
+        // Entry count, then the length of the first name.
+        let table_of_contents_offset =
+            u64::from_ne_bytes(bytes[bytes.len() - 8..].try_into().unwrap()) as usize;
+        let name_len_offset = table_of_contents_offset + 8;
+        bytes[name_len_offset..name_len_offset + 8].copy_from_slice(&u64::MAX.to_ne_bytes());
+        assert!(TaggedArchiveReader::new(Cursor::new(bytes)).is_err());
+    }
+}
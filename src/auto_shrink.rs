@@ -0,0 +1,142 @@
+//! A `TaggedVec` wrapper that releases unused memory after removals.
+
+use std::ops::Deref;
+
+use crate::TaggedVec;
+
+/// A [`TaggedVec`] that shrinks its capacity to fit its length whenever removals leave it sparsely occupied.
+///
+/// After each removing operation, if the length is below `min_occupancy` times the capacity, then the capacity is shrunk to the length.
+/// Read access to the underlying `TaggedVec` is available via [`Deref`].
+pub struct AutoShrinkTaggedVec<Index, Value> {
+    vec: TaggedVec<Index, Value>,
+    min_occupancy: f64,
+}
+
+impl<Index, Value> AutoShrinkTaggedVec<Index, Value> {
+    /// Wraps the given vector, shrinking it whenever its occupancy falls below `min_occupancy`.
+    ///
+    /// Panics if `min_occupancy` is not within `0.0..=1.0`.
+    pub fn new(vec: TaggedVec<Index, Value>, min_occupancy: f64) -> Self {
+        let mut result = Self {
+            vec,
+            min_occupancy: 0.0,
+        };
+        result.set_min_occupancy(min_occupancy);
+        result
+    }
+
+    /// Returns the occupancy below which the vector is shrunk.
+    pub fn min_occupancy(&self) -> f64 {
+        self.min_occupancy
+    }
+
+    /// Sets the occupancy below which the vector is shrunk, and shrinks it if it is already below.
+    ///
+    /// Panics if `min_occupancy` is not within `0.0..=1.0`.
+    pub fn set_min_occupancy(&mut self, min_occupancy: f64) {
+        assert!(
+            (0.0..=1.0).contains(&min_occupancy),
+            "min_occupancy must be within 0.0..=1.0, but is {min_occupancy}"
+        );
+        self.min_occupancy = min_occupancy;
+        self.shrink_if_sparse();
+    }
+
+    /// Inserts the given value at the back of the vector, returning its index.
+    pub fn push(&mut self, value: Value) -> Index
+    where
+        Index: From<usize>,
+    {
+        self.vec.push(value)
+    }
+
+    /// Removes the value at the back of the vector and returns it with its index.
+    pub fn pop(&mut self) -> Option<(Index, Value)>
+    where
+        Index: From<usize>,
+    {
+        let result = self.vec.pop();
+        self.shrink_if_sparse();
+        result
+    }
+
+    /// Removes and returns the value at the given index, shifting all values after it to the left.
+    pub fn remove(&mut self, index: Index) -> Value
+    where
+        Index: Into<usize>,
+    {
+        let result = self.vec.vec.remove(index.into());
+        self.shrink_if_sparse();
+        result
+    }
+
+    /// Retains only the values specified by the predicate.
+    pub fn retain(&mut self, f: impl FnMut(&Value) -> bool) {
+        self.vec.retain(f);
+        self.shrink_if_sparse();
+    }
+
+    /// Shortens the vector to the given length, dropping the values after it.
+    pub fn truncate(&mut self, len: usize) {
+        self.vec.vec.truncate(len);
+        self.shrink_if_sparse();
+    }
+
+    /// Removes all values.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Returns a mutable reference to the value at the given index, or `None` if the index is out of bounds.
+    pub fn get_mut(&mut self, index: Index) -> Option<&mut Value>
+    where
+        Index: Into<usize>,
+    {
+        self.vec.vec.get_mut(index.into())
+    }
+
+    /// Consumes the `AutoShrinkTaggedVec`, returning the underlying `TaggedVec`.
+    pub fn into_inner(self) -> TaggedVec<Index, Value> {
+        self.vec
+    }
+
+    fn shrink_if_sparse(&mut self) {
+        if (self.vec.len() as f64) < self.vec.capacity() as f64 * self.min_occupancy {
+            self.vec.vec.shrink_to_fit();
+        }
+    }
+}
+
+impl<Index, Value> Deref for AutoShrinkTaggedVec<Index, Value> {
+    type Target = TaggedVec<Index, Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AutoShrinkTaggedVec;
+    use crate::TaggedVec;
+
+    #[test]
+    fn auto_shrink() {
+        let mut vec = AutoShrinkTaggedVec::new(TaggedVec::<usize, _>::from_iter(0..100), 0.5);
+        let capacity = vec.capacity();
+
+        vec.truncate(60);
+        assert_eq!(vec.capacity(), capacity);
+        assert_eq!(vec.remove(0), 0);
+        assert_eq!(vec.capacity(), capacity);
+
+        vec.retain(|value| value % 2 == 0);
+        assert_eq!(vec.len(), 29);
+        assert!(vec.capacity() < capacity);
+
+        assert_eq!(vec.push(100), 29);
+        vec.clear();
+        assert_eq!(vec.capacity(), 0);
+    }
+}
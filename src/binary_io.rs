@@ -1,22 +1,63 @@
 //! Functions providing plain binary I/O for `TaggedVec`.
-use std::{io::Read, marker::PhantomData, mem};
+use std::{
+    io::{Error, ErrorKind, Read},
+    marker::PhantomData,
+    mem,
+    ops::RangeBounds,
+};
 
-use crate::TaggedVec;
+use crate::{TaggedVec, mapped_range_bounds::MappedRangeBounds};
 
 impl<Index, Value: Copy> TaggedVec<Index, Value> {
+    /// The maximum number of bytes between two invocations of the progress callback of [`Self::read_binary_with_progress`] and [`Self::write_binary_with_progress`].
+    pub const PROGRESS_CHUNK_SIZE: usize = 1 << 20;
+
     /// Read a `TaggedVec` from the given reader by simply copying the bytes into the underlying vector.
     ///
     /// This is as if the reader was memory-mapped into the vector.
     /// For correct function, the bytes must have been written by [`Self::write_binary`] on a machine with the same pointer size and the same endianness.
-    pub fn read_binary(mut reader: impl Read) -> std::io::Result<Self> {
+    pub fn read_binary(reader: impl Read) -> std::io::Result<Self> {
+        Self::read_binary_with_progress(reader, |_, _| {})
+    }
+
+    /// Like [`Self::read_binary`], but calls `progress` with the number of bytes read so far and the total number of bytes while reading.
+    ///
+    /// The callback is invoked once after the length header is read, and then after every chunk of at most [`Self::PROGRESS_CHUNK_SIZE`] bytes.
+    pub fn read_binary_with_progress(
+        mut reader: impl Read,
+        mut progress: impl FnMut(u64, u64),
+    ) -> std::io::Result<Self> {
         let mut buffer = [0; mem::size_of::<usize>()];
         reader.read_exact(&mut buffer)?;
         let len = usize::from_ne_bytes(buffer);
 
+        let total = (buffer.len() + mem::size_of::<Value>() * len) as u64;
+        progress(buffer.len() as u64, total);
+
+        let data = Self::read_values(&mut reader, len, |data_bytes_len| {
+            progress((buffer.len() + data_bytes_len) as u64, total)
+        })?;
+
+        Ok(Self {
+            index_type: PhantomData,
+            vec: data,
+        })
+    }
+
+    /// Reads `len` values by copying their bytes from the given reader.
+    ///
+    /// Calls `progress` with the number of bytes read so far after every chunk of at most [`Self::PROGRESS_CHUNK_SIZE`] bytes.
+    pub(crate) fn read_values(
+        reader: &mut impl Read,
+        len: usize,
+        mut progress: impl FnMut(usize),
+    ) -> std::io::Result<Vec<Value>> {
         let value_size = mem::size_of::<Value>();
-        let data_bytes_len = value_size * len;
+        let data_bytes_len = value_size.checked_mul(len).ok_or_else(|| {
+            Error::new(ErrorKind::InvalidData, format!("length {len} is too large"))
+        })?;
 
-        let mut data = Vec::<Value>::with_capacity(data_bytes_len);
+        let mut data = Vec::<Value>::with_capacity(len);
         let mut data_bytes = unsafe {
             Vec::from_raw_parts(
                 data.as_mut_ptr() as *mut u8,
@@ -24,33 +65,145 @@ impl<Index, Value: Copy> TaggedVec<Index, Value> {
                 data.capacity() * value_size,
             )
         };
-        reader
-            .by_ref()
-            .take(data_bytes_len.try_into().unwrap())
-            .read_to_end(&mut data_bytes)?;
+        while data_bytes.len() < data_bytes_len {
+            let chunk_len = (data_bytes_len - data_bytes.len()).min(Self::PROGRESS_CHUNK_SIZE);
+            let read_len = reader
+                .by_ref()
+                .take(chunk_len.try_into().unwrap())
+                .read_to_end(&mut data_bytes);
+            if !matches!(read_len, Ok(read_len) if read_len == chunk_len) {
+                // The allocation is owned by `data`.
+                data_bytes.leak();
+                read_len?;
+                return Err(Error::from(ErrorKind::UnexpectedEof));
+            }
+            progress(data_bytes.len());
+        }
         unsafe {
             data.set_len(len);
         };
         data_bytes.leak();
 
-        Ok(Self {
-            index_type: PhantomData,
-            vec: data,
-        })
+        Ok(data)
+    }
+
+    /// Returns the bytes of the given values.
+    pub(crate) fn values_as_bytes(values: &[Value]) -> &[u8] {
+        unsafe {
+            std::slice::from_raw_parts(values.as_ptr() as *const u8, mem::size_of_val(values))
+        }
     }
 
     /// Write a `TaggedVec` into the given writer by simply copying the bytes from the underlying vector.
     ///
     /// Note that this simple data format is as if the vector was memory-mapped into the writer.
     /// The data format is dependent on the machine's pointer size and endianness, so be careful when writing and reading on different machines.
-    pub fn write_binary(&self, mut writer: impl std::io::Write) -> std::io::Result<()> {
-        writer.write_all(&self.len().to_ne_bytes())?;
+    /// Use [`Self::write_binary_portable`] for a format that is independent of the machine.
+    pub fn write_binary(&self, writer: impl std::io::Write) -> std::io::Result<()> {
+        self.write_binary_with_progress(writer, |_, _| {})
+    }
 
-        let value_size = mem::size_of::<Value>();
-        let data_bytes_len = value_size * self.len();
-        let data: &[u8] =
-            unsafe { std::slice::from_raw_parts(self.vec.as_ptr() as *const u8, data_bytes_len) };
-        writer.write_all(data)
+    /// Like [`Self::write_binary`], but calls `progress` with the number of bytes written so far and the total number of bytes while writing.
+    ///
+    /// The callback is invoked once after the length header is written, and then after every chunk of at most [`Self::PROGRESS_CHUNK_SIZE`] bytes.
+    pub fn write_binary_with_progress(
+        &self,
+        mut writer: impl std::io::Write,
+        mut progress: impl FnMut(u64, u64),
+    ) -> std::io::Result<()> {
+        let header = self.len().to_ne_bytes();
+        writer.write_all(&header)?;
+
+        let data = Self::values_as_bytes(&self.vec);
+        let total = (header.len() + data.len()) as u64;
+        progress(header.len() as u64, total);
+
+        let mut written = header.len();
+        for chunk in data.chunks(Self::PROGRESS_CHUNK_SIZE) {
+            writer.write_all(chunk)?;
+            written += chunk.len();
+            progress(written as u64, total);
+        }
+        Ok(())
+    }
+
+    /// Like [`Self::write_binary`], but writes only the values in the given range.
+    ///
+    /// The written data is in the same format as that of [`Self::write_binary`], so it can be read back with [`Self::read_binary`] as a vector of only the selected values.
+    pub fn write_binary_range(
+        &self,
+        mut writer: impl std::io::Write,
+        range: impl RangeBounds<Index>,
+    ) -> std::io::Result<()>
+    where
+        usize: From<Index>,
+        Index: Copy,
+    {
+        let values = &self.vec[MappedRangeBounds::new(range).to_range(self.len())];
+        writer.write_all(&values.len().to_ne_bytes())?;
+        writer.write_all(Self::values_as_bytes(values))
+    }
+
+    /// Like [`Self::read_binary`], but first reads and validates a type header written by [`Self::write_binary_with_type_header`].
+    ///
+    /// Fails with [`ErrorKind::InvalidData`] if the size or alignment of `Value`, or the given `type_tag` differ from the ones stored in the header.
+    /// This prevents silently reinterpreting a file as the wrong value type.
+    pub fn read_binary_with_type_header(
+        mut reader: impl Read,
+        type_tag: u64,
+    ) -> std::io::Result<Self> {
+        let mut buffer = [0; mem::size_of::<u64>()];
+        let mut header = [0; 3];
+        for field in &mut header {
+            reader.read_exact(&mut buffer)?;
+            *field = u64::from_ne_bytes(buffer);
+        }
+
+        let expected = Self::type_header(type_tag);
+        if header != expected {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "type header mismatch: expected (size, alignment, tag) {expected:?}, but found {header:?}"
+                ),
+            ));
+        }
+
+        Self::read_binary(reader)
+    }
+
+    /// Like [`Self::write_binary`], but first writes a type header containing the size and alignment of `Value`, as well as the given `type_tag`.
+    ///
+    /// The `type_tag` is an arbitrary user-provided identifier of the value type, see for example [`Self::type_name_tag`].
+    pub fn write_binary_with_type_header(
+        &self,
+        mut writer: impl std::io::Write,
+        type_tag: u64,
+    ) -> std::io::Result<()> {
+        for field in Self::type_header(type_tag) {
+            writer.write_all(&field.to_ne_bytes())?;
+        }
+        self.write_binary(writer)
+    }
+
+    /// Returns a type tag derived from the [type name](std::any::type_name) of `Value`.
+    ///
+    /// Note that type names are not guaranteed to be stable across compiler versions.
+    pub fn type_name_tag() -> u64 {
+        // 64-bit FNV-1a hash.
+        std::any::type_name::<Value>()
+            .bytes()
+            .fold(0xcbf29ce484222325, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+            })
+    }
+
+    fn type_header(type_tag: u64) -> [u64; 3] {
+        [
+            mem::size_of::<Value>() as u64,
+            mem::align_of::<Value>() as u64,
+            type_tag,
+        ]
     }
 }
 
@@ -70,4 +223,67 @@ mod tests {
         let read_vec = TaggedVec::<usize, u64>::read_binary(buffer.as_slice()).unwrap();
         assert_eq!(read_vec.as_untagged_slice(), &[42, 1337]);
     }
+
+    #[test]
+    fn test_binary_io_range() {
+        let vec = TaggedVec::<usize, u64>::from(vec![1, 2, 3, 4]);
+
+        let mut buffer = Vec::new();
+        vec.write_binary_range(&mut buffer, 2..).unwrap();
+
+        let read_vec = TaggedVec::<usize, u64>::read_binary(buffer.as_slice()).unwrap();
+        assert_eq!(read_vec, [3, 4]);
+    }
+
+    #[test]
+    fn test_binary_io_with_type_header() {
+        let mut vec = TaggedVec::<usize, u64>::new();
+        vec.push(42);
+
+        let mut buffer = Vec::new();
+        vec.write_binary_with_type_header(&mut buffer, TaggedVec::<usize, u64>::type_name_tag())
+            .unwrap();
+
+        let read_vec = TaggedVec::<usize, u64>::read_binary_with_type_header(
+            buffer.as_slice(),
+            TaggedVec::<usize, u64>::type_name_tag(),
+        )
+        .unwrap();
+        assert_eq!(read_vec.as_untagged_slice(), &[42]);
+
+        assert!(
+            TaggedVec::<usize, u32>::read_binary_with_type_header(buffer.as_slice(), 0).is_err()
+        );
+        assert!(
+            TaggedVec::<usize, i64>::read_binary_with_type_header(
+                buffer.as_slice(),
+                TaggedVec::<usize, i64>::type_name_tag()
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_binary_io_with_progress() {
+        let len = TaggedVec::<usize, u64>::PROGRESS_CHUNK_SIZE / 8 + 1;
+        let vec = TaggedVec::<usize, u64>::from(vec![7; len]);
+        let total = (8 + 8 * len) as u64;
+
+        let mut buffer = Vec::new();
+        let mut progress = Vec::new();
+        vec.write_binary_with_progress(&mut buffer, |done, total| progress.push((done, total)))
+            .unwrap();
+        assert_eq!(progress, [(8, total), (total - 8, total), (total, total)]);
+
+        progress.clear();
+        let read_vec =
+            TaggedVec::<usize, u64>::read_binary_with_progress(buffer.as_slice(), |done, total| {
+                progress.push((done, total))
+            })
+            .unwrap();
+        assert_eq!(read_vec, vec);
+        assert_eq!(progress, [(8, total), (total - 8, total), (total, total)]);
+
+        assert!(TaggedVec::<usize, u64>::read_binary(&buffer[..buffer.len() - 1]).is_err());
+    }
 }
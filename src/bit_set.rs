@@ -0,0 +1,280 @@
+//! A set of indices of a `TaggedVec`, stored as a bit vector.
+
+use std::{fmt::Debug, iter, marker::PhantomData};
+
+use crate::TaggedRange;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A set of indices stored as one bit per index.
+///
+/// The set grows automatically to hold the largest inserted index.
+pub struct TaggedBitSet<Index> {
+    index_type: PhantomData<Index>,
+    words: Vec<u64>,
+}
+
+/// An iterator over the indices in a [`TaggedBitSet`] in ascending order.
+pub struct TaggedBitSetIter<'a, Index> {
+    index_type: PhantomData<Index>,
+    words: &'a [u64],
+    /// The index of the first bit of `current`.
+    offset: usize,
+    /// The remaining bits of the current word.
+    current: u64,
+}
+
+impl<Index> TaggedBitSet<Index> {
+    /// Creates a new empty `TaggedBitSet`.
+    pub fn new() -> Self {
+        Self {
+            index_type: PhantomData,
+            words: Vec::new(),
+        }
+    }
+
+    /// Creates a new empty `TaggedBitSet` that can hold the indices `0..capacity` without reallocating.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            index_type: PhantomData,
+            words: Vec::with_capacity(capacity.div_ceil(WORD_BITS)),
+        }
+    }
+
+    /// Returns the number of indices in the set.
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns `true` if the set contains no indices.
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    /// Inserts the given index into the set.
+    ///
+    /// Returns `true` if the index was not contained before.
+    pub fn insert(&mut self, index: Index) -> bool
+    where
+        Index: Into<usize>,
+    {
+        let (word, mask) = Self::position(index.into());
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        let inserted = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        inserted
+    }
+
+    /// Removes the given index from the set.
+    ///
+    /// Returns `true` if the index was contained before.
+    pub fn remove(&mut self, index: Index) -> bool
+    where
+        Index: Into<usize>,
+    {
+        let (word, mask) = Self::position(index.into());
+        let Some(word) = self.words.get_mut(word) else {
+            return false;
+        };
+        let removed = *word & mask != 0;
+        *word &= !mask;
+        removed
+    }
+
+    /// Returns `true` if the set contains the given index.
+    pub fn contains(&self, index: Index) -> bool
+    where
+        Index: Into<usize>,
+    {
+        self.contains_usize(index.into())
+    }
+
+    /// Removes all indices from the set.
+    pub fn clear(&mut self) {
+        self.words.clear();
+    }
+
+    /// Returns an iterator over the indices in the set in ascending order.
+    pub fn iter(&self) -> TaggedBitSetIter<'_, Index> {
+        self.iter_from(0)
+    }
+
+    /// Returns an iterator over the maximal ranges of consecutive indices in the set in ascending order.
+    ///
+    /// This allows to apply bulk operations per range instead of per index.
+    pub fn ranges(&self) -> impl Iterator<Item = TaggedRange<Index>> + '_
+    where
+        Index: From<usize>,
+    {
+        let mut position = 0;
+        iter::from_fn(move || {
+            let start = self.next_bit(position, true)?;
+            let end = self.next_bit(start, false).unwrap();
+            position = end;
+            Some(TaggedRange::new(start.into(), end.into()))
+        })
+    }
+
+    pub(crate) fn contains_usize(&self, index: usize) -> bool {
+        let (word, mask) = Self::position(index);
+        self.words.get(word).is_some_and(|word| word & mask != 0)
+    }
+
+    /// Returns an iterator over the indices in the set that are at least `start`.
+    ///
+    /// The index type of the iterator can be chosen freely, for example `usize` for internal use.
+    pub(crate) fn iter_from<IterIndex>(&self, start: usize) -> TaggedBitSetIter<'_, IterIndex> {
+        let (word, mask) = Self::position(start);
+        let words = self.words.get(word..).unwrap_or_default();
+        TaggedBitSetIter {
+            index_type: PhantomData,
+            current: words.first().map_or(0, |word| word & !(mask - 1)),
+            words: words.get(1..).unwrap_or_default(),
+            offset: start - start % WORD_BITS,
+        }
+    }
+
+    /// Returns the smallest index that is at least `start` and whose bit equals `value`.
+    ///
+    /// Bits beyond the stored words are unset.
+    fn next_bit(&self, start: usize, value: bool) -> Option<usize> {
+        let flip = if value { 0 } else { u64::MAX };
+        let (mut word_index, mask) = Self::position(start);
+        let mut word = (self.words.get(word_index)? ^ flip) & !(mask - 1);
+        loop {
+            if word != 0 {
+                return Some(word_index * WORD_BITS + word.trailing_zeros() as usize);
+            }
+            word_index += 1;
+            match self.words.get(word_index) {
+                Some(next_word) => word = next_word ^ flip,
+                None => return (!value).then_some(word_index * WORD_BITS),
+            }
+        }
+    }
+
+    fn position(index: usize) -> (usize, u64) {
+        (index / WORD_BITS, 1 << (index % WORD_BITS))
+    }
+}
+
+impl<Index: From<usize>> Iterator for TaggedBitSetIter<'_, Index> {
+    type Item = Index;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current == 0 {
+            let (word, words) = self.words.split_first()?;
+            self.current = *word;
+            self.words = words;
+            self.offset += WORD_BITS;
+        }
+
+        let bit = self.current.trailing_zeros() as usize;
+        self.current &= self.current - 1;
+        Some((self.offset + bit).into())
+    }
+}
+
+impl<Index> Clone for TaggedBitSet<Index> {
+    fn clone(&self) -> Self {
+        Self {
+            index_type: PhantomData,
+            words: self.words.clone(),
+        }
+    }
+}
+
+impl<Index> Default for TaggedBitSet<Index> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Index> PartialEq for TaggedBitSet<Index> {
+    fn eq(&self, other: &Self) -> bool {
+        let (shorter, longer) = if self.words.len() <= other.words.len() {
+            (&self.words, &other.words)
+        } else {
+            (&other.words, &self.words)
+        };
+        longer[..shorter.len()] == shorter[..] && longer[shorter.len()..].iter().all(|w| *w == 0)
+    }
+}
+
+impl<Index> Eq for TaggedBitSet<Index> {}
+
+impl<Index> Debug for TaggedBitSet<Index> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TaggedBitSet")?;
+        f.debug_set().entries(self.iter_from::<usize>(0)).finish()
+    }
+}
+
+impl<Index: Into<usize>> Extend<Index> for TaggedBitSet<Index> {
+    fn extend<T: IntoIterator<Item = Index>>(&mut self, iter: T) {
+        for index in iter {
+            self.insert(index);
+        }
+    }
+}
+
+impl<Index: Into<usize>> FromIterator<Index> for TaggedBitSet<Index> {
+    fn from_iter<T: IntoIterator<Item = Index>>(iter: T) -> Self {
+        let mut result = Self::new();
+        result.extend(iter);
+        result
+    }
+}
+
+impl<'a, Index: From<usize>> IntoIterator for &'a TaggedBitSet<Index> {
+    type Item = Index;
+    type IntoIter = TaggedBitSetIter<'a, Index>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TaggedBitSet;
+    use crate::TaggedRange;
+
+    #[test]
+    fn insert_remove_iter() {
+        let mut set: TaggedBitSet<usize> = [3, 64, 200, 3].into_iter().collect();
+        assert_eq!(set.len(), 3);
+        assert!(set.insert(0));
+        assert!(!set.insert(64));
+        assert!(set.remove(200));
+        assert!(!set.remove(1000));
+        assert!(set.contains(3) && !set.contains(4));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0, 3, 64]);
+        assert_eq!(set.iter_from::<usize>(4).collect::<Vec<_>>(), [64]);
+        assert_eq!(set, [0, 3, 64].into_iter().collect());
+        assert_eq!(format!("{set:?}"), "TaggedBitSet{0, 3, 64}");
+    }
+
+    #[test]
+    fn ranges() {
+        let set: TaggedBitSet<usize> = [0, 1, 2, 5, 63, 64, 65, 128].into_iter().collect();
+        assert_eq!(
+            set.ranges().collect::<Vec<_>>(),
+            [
+                TaggedRange::new(0, 3),
+                TaggedRange::new(5, 6),
+                TaggedRange::new(63, 66),
+                TaggedRange::new(128, 129)
+            ]
+        );
+
+        let set: TaggedBitSet<usize> = (0..128).collect();
+        assert_eq!(set.ranges().collect::<Vec<_>>(), [TaggedRange::new(0, 128)]);
+        assert_eq!(TaggedBitSet::<usize>::new().ranges().count(), 0);
+    }
+}
@@ -0,0 +1,108 @@
+//! A `TaggedVec` wrapper with a maximum length.
+
+use std::ops::Deref;
+
+use crate::TaggedVec;
+
+/// A [`TaggedVec`] that refuses to grow beyond a maximum length.
+///
+/// Read access to the underlying `TaggedVec` is available via [`Deref`].
+pub struct BoundedTaggedVec<Index, Value> {
+    vec: TaggedVec<Index, Value>,
+    max_len: usize,
+}
+
+impl<Index, Value> BoundedTaggedVec<Index, Value> {
+    /// Creates a new empty `BoundedTaggedVec` that can hold at most `max_len` values.
+    pub fn with_max_len(max_len: usize) -> Self {
+        Self {
+            vec: TaggedVec::new(),
+            max_len,
+        }
+    }
+
+    /// Returns the maximum number of values this `BoundedTaggedVec` can hold.
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// Returns `true` if the maximum length is reached.
+    pub fn is_full(&self) -> bool {
+        self.vec.len() >= self.max_len
+    }
+
+    /// Inserts the given value at the back of the vector, returning its index.
+    ///
+    /// Returns the value as error if the maximum length is reached.
+    pub fn push(&mut self, value: Value) -> Result<Index, Value>
+    where
+        Index: From<usize>,
+    {
+        if self.is_full() {
+            Err(value)
+        } else {
+            Ok(self.vec.push(value))
+        }
+    }
+
+    /// Inserts the given `value` at position `index`, shifting all existing values in range `index..` one position to the right.
+    ///
+    /// Returns the value as error if the maximum length is reached.
+    pub fn insert(&mut self, index: Index, value: Value) -> Result<(), Value>
+    where
+        Index: Into<usize>,
+    {
+        if self.is_full() {
+            Err(value)
+        } else {
+            self.vec.insert(index, value);
+            Ok(())
+        }
+    }
+
+    /// Removes the value at the back of the vector and returns it with its index.
+    pub fn pop(&mut self) -> Option<(Index, Value)>
+    where
+        Index: From<usize>,
+    {
+        self.vec.pop()
+    }
+
+    /// Returns a mutable reference to the value at the given index, or `None` if the index is out of bounds.
+    pub fn get_mut(&mut self, index: Index) -> Option<&mut Value>
+    where
+        Index: Into<usize>,
+    {
+        self.vec.vec.get_mut(index.into())
+    }
+
+    /// Consumes the `BoundedTaggedVec`, returning the underlying `TaggedVec`.
+    pub fn into_inner(self) -> TaggedVec<Index, Value> {
+        self.vec
+    }
+}
+
+impl<Index, Value> Deref for BoundedTaggedVec<Index, Value> {
+    type Target = TaggedVec<Index, Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BoundedTaggedVec;
+
+    #[test]
+    fn bounded_push() {
+        let mut vec = BoundedTaggedVec::<usize, _>::with_max_len(2);
+        assert_eq!(vec.push('a'), Ok(0));
+        assert_eq!(vec.push('b'), Ok(1));
+        assert_eq!(vec.push('c'), Err('c'));
+        assert_eq!(vec.insert(0, 'c'), Err('c'));
+        assert_eq!(vec.pop(), Some((1, 'b')));
+        assert_eq!(vec.insert(0, 'c'), Ok(()));
+        assert_eq!(vec.as_untagged_slice(), &['c', 'a']);
+    }
+}
@@ -0,0 +1,111 @@
+//! Two-pass construction of `TaggedVec`s.
+
+use std::marker::PhantomData;
+
+use crate::{TaggedRange, TaggedVec};
+
+/// A builder for constructing a [`TaggedVec`] out of order.
+///
+/// Contiguous blocks of indices are reserved with [`Self::reserve_index_block`], and their values are filled in any order with [`Self::set`].
+/// Finally, [`Self::finish`] checks that all reserved slots were filled and returns the `TaggedVec`.
+pub struct TaggedVecBuilder<Index, Value> {
+    slots: Vec<Option<Value>>,
+    index_type: PhantomData<Index>,
+}
+
+impl<Index, Value> TaggedVecBuilder<Index, Value> {
+    /// Creates a new empty `TaggedVecBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of reserved slots.
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns `true` if no slots were reserved.
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Reserves the next `n` indices, returning their range.
+    pub fn reserve_index_block(&mut self, n: usize) -> TaggedRange<Index>
+    where
+        Index: From<usize>,
+    {
+        let start = self.slots.len();
+        self.slots.resize_with(start + n, || None);
+        TaggedRange::new(start.into(), self.slots.len().into())
+    }
+
+    /// Sets the value of a reserved slot, returning the previous value if the slot was already filled.
+    ///
+    /// Panics if the index was not reserved.
+    pub fn set(&mut self, index: Index, value: Value) -> Option<Value>
+    where
+        Index: Into<usize>,
+    {
+        let index = index.into();
+        let slot = self
+            .slots
+            .get_mut(index)
+            .unwrap_or_else(|| panic!("index {index} was not reserved"));
+        slot.replace(value)
+    }
+
+    /// Returns `true` if all reserved slots are filled.
+    pub fn is_complete(&self) -> bool {
+        self.slots.iter().all(Option::is_some)
+    }
+
+    /// Returns the `TaggedVec` with the values of all reserved slots.
+    ///
+    /// Panics if a reserved slot was not filled.
+    pub fn finish(self) -> TaggedVec<Index, Value> {
+        self.slots
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| value.unwrap_or_else(|| panic!("slot {index} was not filled")))
+            .collect()
+    }
+}
+
+impl<Index, Value> Default for TaggedVecBuilder<Index, Value> {
+    fn default() -> Self {
+        Self {
+            slots: Default::default(),
+            index_type: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TaggedVecBuilder;
+    use crate::TaggedRange;
+
+    #[test]
+    fn builder() {
+        let mut builder = TaggedVecBuilder::<usize, _>::new();
+        let first = builder.reserve_index_block(2);
+        let second = builder.reserve_index_block(1);
+        assert_eq!(first, TaggedRange::new(0, 2));
+        assert_eq!(second, TaggedRange::new(2, 3));
+
+        builder.set(2, 'c');
+        builder.set(0, 'a');
+        assert!(!builder.is_complete());
+        builder.set(1, 'b');
+        assert_eq!(builder.finish(), ['a', 'b', 'c']);
+    }
+
+    #[test]
+    #[should_panic = "slot 1 was not filled"]
+    fn builder_incomplete() {
+        let mut builder = TaggedVecBuilder::<usize, _>::new();
+        builder.reserve_index_block(2);
+        builder.set(0, 'a');
+        builder.finish();
+    }
+}
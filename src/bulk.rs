@@ -0,0 +1,141 @@
+//! Bulk access to a `TaggedVec` at lists of indices.
+
+use crate::TaggedVec;
+
+/// Specifies how [`TaggedVec::scatter`] handles multiple entries for the same index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DuplicateIndexPolicy {
+    /// The last entry for an index is kept.
+    #[default]
+    LastWins,
+    /// The first entry for an index is kept.
+    FirstWins,
+    /// Panic if an index occurs more than once.
+    Panic,
+}
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Returns a new `TaggedVec` containing clones of the values at the given indices, in the given order.
+    ///
+    /// The result may be indexed by a different type, since its indices do not correspond to the indices of `self`.
+    /// Panics if any index is out of bounds.
+    pub fn gather<NewIndex>(
+        &self,
+        indices: impl IntoIterator<Item = Index>,
+    ) -> TaggedVec<NewIndex, Value>
+    where
+        Index: Into<usize>,
+        Value: Clone,
+    {
+        indices
+            .into_iter()
+            .map(|index| self.vec[index.into()].clone())
+            .collect()
+    }
+
+    /// Returns references to the values at the given indices, in the given order.
+    ///
+    /// Panics if any index is out of bounds.
+    pub fn select(&self, indices: impl IntoIterator<Item = Index>) -> Vec<&Value>
+    where
+        Index: Into<usize>,
+    {
+        indices
+            .into_iter()
+            .map(|index| &self.vec[index.into()])
+            .collect()
+    }
+
+    /// Returns references to the values at the given indices, in the given order, or `None` if any index is out of bounds.
+    pub fn get_multi(&self, indices: &[Index]) -> Option<Vec<&Value>>
+    where
+        Index: Into<usize> + Copy,
+    {
+        self.get_multi_iter(indices.iter().copied()).collect()
+    }
+
+    /// Returns an iterator over the results of [`Self::get`] for each of the given indices, in the given order.
+    pub fn get_multi_iter(
+        &self,
+        indices: impl IntoIterator<Item = Index>,
+    ) -> impl Iterator<Item = Option<&Value>>
+    where
+        Index: Into<usize>,
+    {
+        indices.into_iter().map(|index| self.get(index))
+    }
+
+    /// Writes each value to its index, overwriting the value stored there.
+    ///
+    /// If an index occurs more than once, then the `policy` decides which value is kept.
+    /// Panics if any index is out of bounds, or on a duplicate index with [`DuplicateIndexPolicy::Panic`].
+    /// The entries are written one by one, so on a panic the vector is left partially updated with the entries before the offending one.
+    pub fn scatter(
+        &mut self,
+        entries: impl IntoIterator<Item = (Index, Value)>,
+        policy: DuplicateIndexPolicy,
+    ) where
+        Index: Into<usize>,
+    {
+        if policy == DuplicateIndexPolicy::LastWins {
+            for (index, value) in entries {
+                self.vec[index.into()] = value;
+            }
+            return;
+        }
+
+        let mut written = vec![false; self.vec.len()];
+        for (index, value) in entries {
+            let index = index.into();
+            if written[index] {
+                assert!(
+                    policy != DuplicateIndexPolicy::Panic,
+                    "duplicate index {index} in scatter"
+                );
+            } else {
+                written[index] = true;
+                self.vec[index] = value;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DuplicateIndexPolicy, TaggedVec};
+
+    #[test]
+    fn gather_and_select() {
+        let vec = TaggedVec::<usize, _>::from(vec!['a', 'b', 'c', 'd']);
+        let gathered: TaggedVec<u8, _> = vec.gather([3, 0, 0]);
+        assert_eq!(gathered, ['d', 'a', 'a']);
+        assert_eq!(vec.select([2, 1]), [&'c', &'b']);
+    }
+
+    #[test]
+    fn get_multi() {
+        let vec = TaggedVec::<usize, _>::from(vec!['a', 'b', 'c']);
+        assert_eq!(vec.get_multi(&[2, 0]), Some(vec![&'c', &'a']));
+        assert_eq!(vec.get_multi(&[2, 3]), None);
+        assert_eq!(
+            vec.get_multi_iter([3, 1]).collect::<Vec<_>>(),
+            [None, Some(&'b')]
+        );
+    }
+
+    #[test]
+    fn scatter() {
+        let mut vec = TaggedVec::<usize, _>::from(vec![0; 4]);
+        vec.scatter([(1, 1), (3, 2), (1, 3)], DuplicateIndexPolicy::LastWins);
+        assert_eq!(vec, [0, 3, 0, 2]);
+        vec.scatter([(0, 4), (0, 5)], DuplicateIndexPolicy::FirstWins);
+        assert_eq!(vec, [4, 3, 0, 2]);
+    }
+
+    #[test]
+    #[should_panic = "duplicate index 2 in scatter"]
+    fn scatter_duplicate_panics() {
+        let mut vec = TaggedVec::<usize, _>::from(vec![0; 4]);
+        vec.scatter([(2, 1), (2, 1)], DuplicateIndexPolicy::Panic);
+    }
+}
@@ -0,0 +1,162 @@
+//! A `TaggedVec` variant allocating from a [`bumpalo`] arena.
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use bumpalo::Bump;
+
+use crate::{EntryIterator, EntryIteratorMut};
+
+/// A vector indexed by `Index` whose values are allocated in a [`Bump`] arena.
+///
+/// This is useful for many short-lived small vectors, since allocating from an arena is much cheaper than allocating from the global allocator.
+/// The memory is only released when the arena is reset or dropped.
+pub struct TaggedBumpVec<'bump, Index, Value> {
+    index_type: PhantomData<Index>,
+    vec: bumpalo::collections::Vec<'bump, Value>,
+}
+
+impl<'bump, Index, Value> TaggedBumpVec<'bump, Index, Value> {
+    /// Creates a new empty `TaggedBumpVec` allocating from the given arena.
+    pub fn new_in(bump: &'bump Bump) -> Self {
+        Self {
+            index_type: PhantomData,
+            vec: bumpalo::collections::Vec::new_in(bump),
+        }
+    }
+
+    /// Creates a new empty `TaggedBumpVec` with at least the given capacity, allocating from the given arena.
+    pub fn with_capacity_in(capacity: usize, bump: &'bump Bump) -> Self {
+        Self {
+            index_type: PhantomData,
+            vec: bumpalo::collections::Vec::with_capacity_in(capacity, bump),
+        }
+    }
+
+    /// Returns the number of elements in the vector.
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Returns `true` if the vector contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Returns a slice of the values in the vector, indexed by `usize`.
+    pub fn as_untagged_slice(&self) -> &[Value] {
+        &self.vec
+    }
+
+    /// Returns a mutable slice of the values in the vector, indexed by `usize`.
+    pub fn as_untagged_mut_slice(&mut self) -> &mut [Value] {
+        &mut self.vec
+    }
+
+    /// Inserts the given value at the back of the vector, returning its index.
+    pub fn push(&mut self, value: Value) -> Index
+    where
+        Index: From<usize>,
+    {
+        let index = self.vec.len().into();
+        self.vec.push(value);
+        index
+    }
+
+    /// Removes the value at the back of the vector and returns it with its index.
+    pub fn pop(&mut self) -> Option<(Index, Value)>
+    where
+        Index: From<usize>,
+    {
+        let value = self.vec.pop()?;
+        Some((self.vec.len().into(), value))
+    }
+
+    /// Returns a reference to the value at the given index, or `None` if the index is out of bounds.
+    pub fn get(&self, index: Index) -> Option<&Value>
+    where
+        Index: Into<usize>,
+    {
+        self.vec.get(index.into())
+    }
+
+    /// Returns a mutable reference to the value at the given index, or `None` if the index is out of bounds.
+    pub fn get_mut(&mut self, index: Index) -> Option<&mut Value>
+    where
+        Index: Into<usize>,
+    {
+        self.vec.get_mut(index.into())
+    }
+
+    /// Returns an iterator over references to the entries of the vector in order of their indices.
+    pub fn iter(&self) -> EntryIterator<'_, Index, Value>
+    where
+        Index: From<usize>,
+    {
+        EntryIterator::new(&self.vec)
+    }
+
+    /// Returns an iterator over mutable references to the entries of the vector in order of their indices.
+    pub fn iter_mut(&mut self) -> EntryIteratorMut<'_, Index, Value>
+    where
+        Index: From<usize>,
+    {
+        EntryIteratorMut::new(&mut self.vec)
+    }
+
+    /// Returns an iterator over references to the values of the vector in order of their indices.
+    pub fn iter_values(&self) -> std::slice::Iter<'_, Value> {
+        self.vec.iter()
+    }
+
+    /// Converts the vector into a slice allocated in the arena, indexed by `usize`.
+    ///
+    /// The slice lives as long as the arena and does not run the destructors of its values.
+    pub fn into_bump_slice(self) -> &'bump [Value] {
+        self.vec.into_bump_slice()
+    }
+}
+
+impl<Index, Value> Extend<Value> for TaggedBumpVec<'_, Index, Value> {
+    fn extend<T: IntoIterator<Item = Value>>(&mut self, iter: T) {
+        self.vec.extend(iter);
+    }
+}
+
+impl<Index, Value: Debug> Debug for TaggedBumpVec<'_, Index, Value> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TaggedBumpVec{:?}", self.vec)
+    }
+}
+
+impl<Index: Into<usize>, Value> std::ops::Index<Index> for TaggedBumpVec<'_, Index, Value> {
+    type Output = Value;
+
+    fn index(&self, index: Index) -> &Self::Output {
+        &self.vec[index.into()]
+    }
+}
+
+impl<Index: Into<usize>, Value> std::ops::IndexMut<Index> for TaggedBumpVec<'_, Index, Value> {
+    fn index_mut(&mut self, index: Index) -> &mut Self::Output {
+        &mut self.vec[index.into()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bumpalo::Bump;
+
+    use super::TaggedBumpVec;
+
+    #[test]
+    fn push_and_index() {
+        let bump = Bump::new();
+        let mut vec = TaggedBumpVec::<usize, _>::new_in(&bump);
+        assert_eq!(vec.push('a'), 0);
+        assert_eq!(vec.push('b'), 1);
+        vec[0] = 'c';
+        assert_eq!(vec.iter().collect::<Vec<_>>(), [(0, &'c'), (1, &'b')]);
+        assert_eq!(vec.pop(), Some((1, 'b')));
+        assert_eq!(vec.into_bump_slice(), &['c']);
+    }
+}
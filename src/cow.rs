@@ -0,0 +1,98 @@
+//! A copy-on-write `TaggedVec`.
+
+use std::{ops::Deref, sync::Arc};
+
+use crate::TaggedVec;
+
+/// A [`TaggedVec`] whose clones share their values until one of them is mutated.
+///
+/// Cloning is O(1), so it is cheap to take many snapshots of a vector that rarely changes.
+/// Mutation goes through [`Self::make_mut`], which clones the values only if they are shared.
+/// Read access to the underlying `TaggedVec` is available via [`Deref`].
+pub struct CowTaggedVec<Index, Value> {
+    vec: Arc<TaggedVec<Index, Value>>,
+}
+
+impl<Index, Value> CowTaggedVec<Index, Value> {
+    /// Creates a new `CowTaggedVec` containing the values of the given `TaggedVec`.
+    pub fn new(vec: TaggedVec<Index, Value>) -> Self {
+        Self { vec: Arc::new(vec) }
+    }
+
+    /// Returns a mutable reference to the underlying `TaggedVec`.
+    ///
+    /// If the values are shared with other clones, they are cloned first, such that the other clones remain unchanged.
+    pub fn make_mut(&mut self) -> &mut TaggedVec<Index, Value>
+    where
+        Value: Clone,
+    {
+        Arc::make_mut(&mut self.vec)
+    }
+
+    /// Returns `true` if the values are shared with another clone of this `CowTaggedVec`.
+    pub fn is_shared(&self) -> bool {
+        Arc::strong_count(&self.vec) > 1
+    }
+
+    /// Returns `true` if both `CowTaggedVec`s share the same values.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.vec, &other.vec)
+    }
+
+    /// Consumes the `CowTaggedVec`, returning the underlying `TaggedVec`.
+    ///
+    /// The values are cloned only if they are shared with other clones.
+    pub fn into_inner(self) -> TaggedVec<Index, Value>
+    where
+        Value: Clone,
+    {
+        Arc::unwrap_or_clone(self.vec)
+    }
+}
+
+impl<Index, Value> Clone for CowTaggedVec<Index, Value> {
+    fn clone(&self) -> Self {
+        Self {
+            vec: self.vec.clone(),
+        }
+    }
+}
+
+impl<Index, Value> Default for CowTaggedVec<Index, Value> {
+    fn default() -> Self {
+        Self::new(TaggedVec::new())
+    }
+}
+
+impl<Index, Value> Deref for CowTaggedVec<Index, Value> {
+    type Target = TaggedVec<Index, Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+impl<Index, Value> From<TaggedVec<Index, Value>> for CowTaggedVec<Index, Value> {
+    fn from(value: TaggedVec<Index, Value>) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CowTaggedVec;
+    use crate::TaggedVec;
+
+    #[test]
+    fn copy_on_write() {
+        let mut vec = CowTaggedVec::from(TaggedVec::<usize, _>::from(vec![1, 2, 3]));
+        let snapshot = vec.clone();
+        assert!(vec.ptr_eq(&snapshot));
+
+        vec.make_mut().push(4);
+        assert!(!vec.ptr_eq(&snapshot));
+        assert!(!vec.is_shared());
+        assert_eq!(*snapshot, [1, 2, 3]);
+        assert_eq!(vec.into_inner(), [1, 2, 3, 4]);
+    }
+}
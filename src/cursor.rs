@@ -0,0 +1,116 @@
+//! A cursor for mutating a `TaggedVec` while iterating over it.
+
+use std::marker::PhantomData;
+
+use crate::TaggedVec;
+
+/// A cursor over a [`TaggedVec`] that allows to remove and insert values while walking over it.
+///
+/// The cursor points either to a value or past the end of the vector.
+/// Index shifts caused by removals and insertions are handled by the cursor, such that each original value is visited exactly once when advancing with [`Self::move_next`].
+pub struct CursorMut<'a, Index, Value> {
+    vec: &'a mut Vec<Value>,
+    position: usize,
+    index_type: PhantomData<Index>,
+}
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Returns a cursor pointing to the first value of the `TaggedVec`.
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, Index, Value> {
+        CursorMut {
+            vec: &mut self.vec,
+            position: 0,
+            index_type: PhantomData,
+        }
+    }
+}
+
+impl<Index, Value> CursorMut<'_, Index, Value> {
+    /// Returns the index the cursor points to, which is the length of the vector if the cursor is past the end.
+    pub fn index(&self) -> Index
+    where
+        Index: From<usize>,
+    {
+        self.position.into()
+    }
+
+    /// Returns the current entry, or `None` if the cursor is past the end.
+    pub fn current(&mut self) -> Option<(Index, &mut Value)>
+    where
+        Index: From<usize>,
+    {
+        self.vec
+            .get_mut(self.position)
+            .map(|value| (self.position.into(), value))
+    }
+
+    /// Moves the cursor to the next value.
+    ///
+    /// Does nothing if the cursor is past the end.
+    pub fn move_next(&mut self) {
+        self.position = (self.position + 1).min(self.vec.len());
+    }
+
+    /// Moves the cursor to the given index.
+    ///
+    /// Panics if the index is greater than the length of the vector.
+    pub fn seek(&mut self, index: Index)
+    where
+        Index: Into<usize>,
+    {
+        let index = index.into();
+        assert!(index <= self.vec.len(), "seek index out of bounds");
+        self.position = index;
+    }
+
+    /// Removes the current value and returns it, moving the cursor to the value that followed it.
+    ///
+    /// Returns `None` if the cursor is past the end.
+    pub fn remove_current(&mut self) -> Option<Value> {
+        if self.position < self.vec.len() {
+            Some(self.vec.remove(self.position))
+        } else {
+            None
+        }
+    }
+
+    /// Inserts the given value after the current value, without moving the cursor.
+    ///
+    /// The inserted value is visited by the next call to [`Self::move_next`].
+    /// If the cursor is past the end, then the value is appended and the cursor stays past the end.
+    pub fn insert_after(&mut self, value: Value) {
+        if self.position < self.vec.len() {
+            self.vec.insert(self.position + 1, value);
+        } else {
+            self.vec.push(value);
+            self.position += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TaggedVec;
+
+    #[test]
+    fn cursor_mut() {
+        let mut vec = TaggedVec::<usize, _>::from(vec![1, 2, 3, 4]);
+        let mut cursor = vec.cursor_mut();
+        while let Some((_, value)) = cursor.current() {
+            if *value % 2 == 0 {
+                cursor.remove_current();
+            } else {
+                *value *= 10;
+                cursor.insert_after(0);
+                cursor.move_next();
+                cursor.move_next();
+            }
+        }
+        assert_eq!(cursor.index(), 4);
+        cursor.insert_after(5);
+        cursor.seek(1);
+        assert_eq!(cursor.current(), Some((1, &mut 0)));
+
+        assert_eq!(vec, [10, 0, 30, 0, 5]);
+    }
+}
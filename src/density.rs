@@ -0,0 +1,150 @@
+//! Construction of `TaggedVec`s from entries or slots filled out of order.
+
+use std::fmt;
+
+use crate::TaggedVec;
+
+/// The error returned by [`TaggedVec::try_from_unordered_entries`] if the indices of the entries do not form exactly the range `0..n`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexDensityError {
+    /// The indices below the largest index that have no entry, in ascending order.
+    pub missing: Vec<usize>,
+    /// The indices that have more than one entry, in ascending order.
+    pub duplicate: Vec<usize>,
+}
+
+impl fmt::Display for IndexDensityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "entries do not cover a dense index range")?;
+        if !self.missing.is_empty() {
+            write!(f, "; missing indices {:?}", self.missing)?;
+        }
+        if !self.duplicate.is_empty() {
+            write!(f, "; duplicate indices {:?}", self.duplicate)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for IndexDensityError {}
+
+/// The error returned by [`TaggedVec::try_from_options`] if some slots are `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingIndices<Index> {
+    /// The indices of the slots that are `None`, in ascending order.
+    pub indices: Vec<Index>,
+}
+
+impl<Index: fmt::Debug> fmt::Display for MissingIndices<Index> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "missing values at indices {:?}", self.indices)
+    }
+}
+
+impl<Index: fmt::Debug> std::error::Error for MissingIndices<Index> {}
+
+impl<Index: Into<usize>, Value> TaggedVec<Index, Value> {
+    /// Creates a `TaggedVec` from entries in any order, e.g. as produced by parallel workers.
+    ///
+    /// The entries are sorted by index, and each value is placed at its index.
+    /// Unlike collecting via [`FromIterator`], this does not panic if the indices do not form exactly the range `0..n`, but returns an error listing all missing and duplicate indices.
+    pub fn try_from_unordered_entries(
+        entries: Vec<(Index, Value)>,
+    ) -> Result<Self, IndexDensityError> {
+        let mut entries: Vec<_> = entries
+            .into_iter()
+            .map(|(index, value)| (index.into(), value))
+            .collect();
+        entries.sort_by_key(|(index, _)| *index);
+
+        let mut error = IndexDensityError {
+            missing: Vec::new(),
+            duplicate: Vec::new(),
+        };
+        let mut expected_index = 0;
+        for (index, _) in &entries {
+            if *index < expected_index {
+                if error.duplicate.last() != Some(index) {
+                    error.duplicate.push(*index);
+                }
+            } else {
+                error.missing.extend(expected_index..*index);
+                expected_index = *index + 1;
+            }
+        }
+
+        if error.missing.is_empty() && error.duplicate.is_empty() {
+            Ok(entries.into_iter().map(|(_, value)| value).collect())
+        } else {
+            Err(error)
+        }
+    }
+}
+
+impl<Index: From<usize>, Value> TaggedVec<Index, Value> {
+    /// Creates a `TaggedVec` from a fully populated vector of optional values, e.g. a staging vector filled out of order.
+    ///
+    /// Returns an error listing the indices of all `None` values if there are any.
+    pub fn try_from_options(options: Vec<Option<Value>>) -> Result<Self, MissingIndices<Index>> {
+        if options.iter().all(Option::is_some) {
+            Ok(options.into_iter().flatten().collect())
+        } else {
+            Err(MissingIndices {
+                indices: options
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, option)| option.is_none())
+                    .map(|(index, _)| index.into())
+                    .collect(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IndexDensityError, MissingIndices};
+    use crate::TaggedVec;
+
+    #[test]
+    fn try_from_unordered_entries() {
+        let vec =
+            TaggedVec::<usize, _>::try_from_unordered_entries(vec![(1, 'b'), (2, 'c'), (0, 'a')]);
+        assert_eq!(vec.unwrap(), ['a', 'b', 'c']);
+
+        let error = TaggedVec::<usize, _>::try_from_unordered_entries(vec![
+            (4, 'e'),
+            (1, 'b'),
+            (1, 'c'),
+            (1, 'd'),
+        ])
+        .unwrap_err();
+        assert_eq!(
+            error,
+            IndexDensityError {
+                missing: vec![0, 2, 3],
+                duplicate: vec![1],
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "entries do not cover a dense index range; missing indices [0, 2, 3]; duplicate indices [1]"
+        );
+    }
+
+    #[test]
+    fn try_from_options() {
+        let vec = TaggedVec::<usize, _>::try_from_options(vec![Some('a'), Some('b')]);
+        assert_eq!(vec.unwrap(), ['a', 'b']);
+
+        let error =
+            TaggedVec::<usize, _>::try_from_options(vec![None, Some('b'), None]).unwrap_err();
+        assert_eq!(
+            error,
+            MissingIndices {
+                indices: vec![0, 2]
+            }
+        );
+        assert_eq!(error.to_string(), "missing values at indices [0, 2]");
+    }
+}
@@ -0,0 +1,129 @@
+//! Computing and applying edit scripts between `TaggedVec`s.
+
+use crate::TaggedVec;
+
+/// A single step of an edit script as produced by [`TaggedVec::diff`].
+///
+/// The index of each edit refers to the state of the vector after all previous edits of the script were applied.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Edit<Index, Value> {
+    /// Insert `value` at `index`, shifting all values in range `index..` one position to the right.
+    Insert {
+        /// The index the value is inserted at.
+        index: Index,
+        /// The inserted value.
+        value: Value,
+    },
+    /// Remove the value at `index`, shifting all values in range `index + 1..` one position to the left.
+    Remove {
+        /// The index of the removed value.
+        index: Index,
+    },
+    /// Replace the value at `index` with `value`.
+    Replace {
+        /// The index of the replaced value.
+        index: Index,
+        /// The new value.
+        value: Value,
+    },
+}
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Computes an edit script that transforms `self` into `other`.
+    ///
+    /// Common prefixes and suffixes are skipped, and the remaining differing region is transformed by replacing values pairwise and inserting or removing the surplus.
+    /// This runs in linear time and produces minimal scripts for the common case of a single contiguous change, but is not guaranteed to be minimal in general.
+    pub fn diff(&self, other: &Self) -> Vec<Edit<Index, Value>>
+    where
+        Index: From<usize>,
+        Value: Clone + PartialEq,
+    {
+        let old = self.as_untagged_slice();
+        let new = other.as_untagged_slice();
+
+        let prefix_len = old
+            .iter()
+            .zip(new)
+            .take_while(|(old, new)| old == new)
+            .count();
+        let old = &old[prefix_len..];
+        let new = &new[prefix_len..];
+        let suffix_len = old
+            .iter()
+            .rev()
+            .zip(new.iter().rev())
+            .take_while(|(old, new)| old == new)
+            .count();
+        let old = &old[..old.len() - suffix_len];
+        let new = &new[..new.len() - suffix_len];
+
+        let mut edits = Vec::new();
+        for (offset, (old_value, new_value)) in old.iter().zip(new).enumerate() {
+            if old_value != new_value {
+                edits.push(Edit::Replace {
+                    index: (prefix_len + offset).into(),
+                    value: new_value.clone(),
+                });
+            }
+        }
+
+        let common_len = old.len().min(new.len());
+        for _ in common_len..old.len() {
+            edits.push(Edit::Remove {
+                index: (prefix_len + common_len).into(),
+            });
+        }
+        for (offset, value) in new.iter().enumerate().skip(common_len) {
+            edits.push(Edit::Insert {
+                index: (prefix_len + offset).into(),
+                value: value.clone(),
+            });
+        }
+
+        edits
+    }
+
+    /// Applies the given edit script, for example one produced by [`Self::diff`].
+    pub fn apply_diff(&mut self, edits: impl IntoIterator<Item = Edit<Index, Value>>)
+    where
+        Index: Into<usize>,
+    {
+        for edit in edits {
+            match edit {
+                Edit::Insert { index, value } => self.vec.insert(index.into(), value),
+                Edit::Remove { index } => {
+                    self.vec.remove(index.into());
+                }
+                Edit::Replace { index, value } => self.vec[index.into()] = value,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Edit, TaggedVec};
+
+    #[test]
+    fn diff_roundtrip() {
+        let old = TaggedVec::<usize, _>::from(vec![1, 2, 3, 4, 5]);
+        for new in [
+            vec![1, 2, 3, 4, 5],
+            vec![1, 2, 9, 4, 5],
+            vec![1, 2, 5],
+            vec![0, 1, 2, 3, 4, 5, 6],
+            vec![7, 8],
+            vec![],
+        ] {
+            let new = TaggedVec::from(new);
+            let mut patched = old.clone();
+            patched.apply_diff(old.diff(&new));
+            assert_eq!(patched, new);
+        }
+
+        assert_eq!(
+            old.diff(&vec![1, 2, 5].into()),
+            [Edit::Remove { index: 2 }, Edit::Remove { index: 2 }]
+        );
+    }
+}
@@ -0,0 +1,47 @@
+//! Detection of values with colliding keys in a `TaggedVec`.
+
+use std::{collections::HashMap, hash::Hash};
+
+use crate::TaggedVec;
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Returns the groups of indices whose values map to equal keys under `f`.
+    ///
+    /// Only groups with at least two members are returned.
+    /// The groups are ordered by their first index, and the indices within each group are ascending.
+    pub fn find_duplicates<K: Hash + Eq>(&self, mut f: impl FnMut(&Value) -> K) -> Vec<Vec<Index>>
+    where
+        Index: From<usize>,
+    {
+        let mut group_ids = HashMap::new();
+        let mut groups = Vec::<Vec<usize>>::new();
+        for (index, value) in self.vec.iter().enumerate() {
+            let group_id = *group_ids.entry(f(value)).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group_id].push(index);
+        }
+
+        groups
+            .into_iter()
+            .filter(|group| group.len() >= 2)
+            .map(|group| group.into_iter().map(Index::from).collect())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TaggedVec;
+
+    #[test]
+    fn find_duplicates() {
+        let vec = TaggedVec::<usize, _>::from(vec!["a", "b", "A", "c", "B", "a"]);
+        assert_eq!(
+            vec.find_duplicates(|value| value.to_lowercase()),
+            [vec![0, 2, 5], vec![1, 4]]
+        );
+        assert!(vec.find_duplicates(|value| *value).len() == 1);
+    }
+}
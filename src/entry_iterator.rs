@@ -0,0 +1,77 @@
+//! Iterators over the entries of a `TaggedVec`, yielding each value together with its index.
+
+use std::{iter::Enumerate, marker::PhantomData};
+
+/// An iterator over references to the entries of a `TaggedVec`.
+pub struct EntryIterator<'a, Index, Value> {
+    iter: Enumerate<std::slice::Iter<'a, Value>>,
+    marker: PhantomData<Index>,
+}
+
+/// An iterator over mutable references to the entries of a `TaggedVec`.
+pub struct EntryIteratorMut<'a, Index, Value> {
+    iter: Enumerate<std::slice::IterMut<'a, Value>>,
+    marker: PhantomData<Index>,
+}
+
+impl<'a, Index, Value> EntryIterator<'a, Index, Value> {
+    pub(crate) fn new(slice: &'a [Value]) -> Self {
+        Self {
+            iter: slice.iter().enumerate(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, Index, Value> EntryIteratorMut<'a, Index, Value> {
+    pub(crate) fn new(slice: &'a mut [Value]) -> Self {
+        Self {
+            iter: slice.iter_mut().enumerate(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, Index: From<usize>, Value> Iterator for EntryIterator<'a, Index, Value> {
+    type Item = (Index, &'a Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(index, value)| (index.into(), value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<Index: From<usize>, Value> DoubleEndedIterator for EntryIterator<'_, Index, Value> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|(index, value)| (index.into(), value))
+    }
+}
+
+impl<Index: From<usize>, Value> ExactSizeIterator for EntryIterator<'_, Index, Value> {}
+
+impl<'a, Index: From<usize>, Value> Iterator for EntryIteratorMut<'a, Index, Value> {
+    type Item = (Index, &'a mut Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(index, value)| (index.into(), value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<Index: From<usize>, Value> DoubleEndedIterator for EntryIteratorMut<'_, Index, Value> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|(index, value)| (index.into(), value))
+    }
+}
+
+impl<Index: From<usize>, Value> ExactSizeIterator for EntryIteratorMut<'_, Index, Value> {}
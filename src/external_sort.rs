@@ -0,0 +1,184 @@
+//! Sorting of binary `TaggedVec` files that do not fit into memory.
+
+use std::{
+    cmp::Ordering,
+    fs::File,
+    io::{BufWriter, Read, Write},
+    mem,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering as AtomicOrdering},
+};
+
+use crate::TaggedVec;
+
+/// Temporary files holding sorted runs, which are deleted on drop.
+struct RunFiles {
+    paths: Vec<PathBuf>,
+}
+
+impl Drop for RunFiles {
+    fn drop(&mut self) {
+        for path in &self.paths {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+/// A sorted run that is read back block by block during merging.
+struct Run<Value> {
+    file: File,
+    remaining: usize,
+    block: Vec<Value>,
+    position: usize,
+}
+
+impl<Value: Copy> Run<Value> {
+    /// Returns the next value of the run without consuming it, reading the next block if necessary.
+    fn peek(&mut self, block_len: usize) -> std::io::Result<Option<&Value>> {
+        if self.position == self.block.len() && self.remaining > 0 {
+            let len = self.remaining.min(block_len);
+            self.block = TaggedVec::<usize, Value>::read_values(&mut self.file, len, |_| {})?;
+            self.remaining -= len;
+            self.position = 0;
+        }
+        Ok(self.block.get(self.position))
+    }
+}
+
+impl<Index, Value: Copy> TaggedVec<Index, Value> {
+    /// Sorts a `TaggedVec` that is stored in the format of [`Self::write_binary`], without loading it into memory at once.
+    ///
+    /// The values are read from `reader`, and the sorted `TaggedVec` is written to `writer` in the same format.
+    /// At most `mem_budget` bytes of values are held in memory at any time.
+    /// If the values do not fit into the budget, then sorted runs are spilled to temporary files in `dir` and merged afterwards.
+    /// The temporary files are deleted before returning, also if an error occurs.
+    ///
+    /// The sort is stable.
+    /// Merging compares the heads of all runs for each value, so the budget should not be much smaller than a hundredth of the data.
+    pub fn sort_external(
+        mut reader: impl Read,
+        writer: impl Write,
+        dir: impl AsRef<Path>,
+        mem_budget: usize,
+        mut cmp: impl FnMut(&Value, &Value) -> Ordering,
+    ) -> std::io::Result<()> {
+        static NEXT_SORT_ID: AtomicUsize = AtomicUsize::new(0);
+
+        let mut buffer = [0; mem::size_of::<usize>()];
+        reader.read_exact(&mut buffer)?;
+        let len = usize::from_ne_bytes(buffer);
+        let chunk_len = (mem_budget / mem::size_of::<Value>().max(1)).max(1);
+        let mut writer = BufWriter::new(writer);
+
+        if len <= chunk_len {
+            let mut values = Self::read_values(&mut reader, len, |_| {})?;
+            values.sort_by(cmp);
+            writer.write_all(&buffer)?;
+            writer.write_all(Self::values_as_bytes(&values))?;
+            return writer.flush();
+        }
+
+        let sort_id = NEXT_SORT_ID.fetch_add(1, AtomicOrdering::Relaxed);
+        let mut run_files = RunFiles { paths: Vec::new() };
+        let mut run_lens = Vec::new();
+        let mut offset = 0;
+        while offset < len {
+            let run_len = chunk_len.min(len - offset);
+            let mut values = Self::read_values(&mut reader, run_len, |_| {})?;
+            values.sort_by(&mut cmp);
+
+            let path = dir.as_ref().join(format!(
+                "tagged-vec-sort-{}-{sort_id}-{}.run",
+                std::process::id(),
+                run_lens.len()
+            ));
+            run_files.paths.push(path.clone());
+            let mut file = BufWriter::new(File::create(path)?);
+            file.write_all(Self::values_as_bytes(&values))?;
+            file.flush()?;
+
+            run_lens.push(run_len);
+            offset += run_len;
+        }
+
+        // One block per run plus one output block.
+        let block_len = (chunk_len / (run_lens.len() + 1)).max(1);
+        let mut runs = run_files
+            .paths
+            .iter()
+            .zip(run_lens)
+            .map(|(path, remaining)| {
+                Ok(Run {
+                    file: File::open(path)?,
+                    remaining,
+                    block: Vec::new(),
+                    position: 0,
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+
+        writer.write_all(&buffer)?;
+        let mut output = Vec::with_capacity(block_len);
+        loop {
+            let mut min: Option<(usize, Value)> = None;
+            for (run_index, run) in runs.iter_mut().enumerate() {
+                if let Some(value) = run.peek(block_len)? {
+                    if min
+                        .as_ref()
+                        .is_none_or(|(_, min)| cmp(value, min) == Ordering::Less)
+                    {
+                        min = Some((run_index, *value));
+                    }
+                }
+            }
+            let Some((run_index, value)) = min else {
+                break;
+            };
+            runs[run_index].position += 1;
+
+            output.push(value);
+            if output.len() == block_len {
+                writer.write_all(Self::values_as_bytes(&output))?;
+                output.clear();
+            }
+        }
+        writer.write_all(Self::values_as_bytes(&output))?;
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TaggedVec;
+
+    #[test]
+    fn sort_external() {
+        let dir = std::env::temp_dir().join(format!("tagged-vec-sort-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let values: Vec<(u32, u32)> = (0..100).map(|i| ((i * 37) % 10, i)).collect();
+        let mut input = Vec::new();
+        TaggedVec::<usize, _>::from(values.clone())
+            .write_binary(&mut input)
+            .unwrap();
+
+        let mut output = Vec::new();
+        TaggedVec::<usize, (u32, u32)>::sort_external(
+            input.as_slice(),
+            &mut output,
+            &dir,
+            7 * 8,
+            |a, b| a.0.cmp(&b.0),
+        )
+        .unwrap();
+
+        let mut expected = values;
+        expected.sort_by_key(|value| value.0);
+        assert_eq!(
+            TaggedVec::<usize, (u32, u32)>::read_binary(output.as_slice()).unwrap(),
+            expected
+        );
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+        std::fs::remove_dir(&dir).unwrap();
+    }
+}
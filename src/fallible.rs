@@ -0,0 +1,95 @@
+//! Iteration helpers with fallible closures.
+
+use crate::TaggedVec;
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Calls `f` on each entry in order of their indices, stopping at the first error.
+    pub fn try_for_each_with_index<Error>(
+        &self,
+        mut f: impl FnMut(Index, &Value) -> Result<(), Error>,
+    ) -> Result<(), Error>
+    where
+        Index: From<usize>,
+    {
+        self.vec
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, value)| f(index.into(), value))
+    }
+
+    /// Calls `f` on each entry in order of their indices with a mutable reference to the value, stopping at the first error.
+    ///
+    /// Modifications made before the error are kept.
+    pub fn try_for_each_with_index_mut<Error>(
+        &mut self,
+        mut f: impl FnMut(Index, &mut Value) -> Result<(), Error>,
+    ) -> Result<(), Error>
+    where
+        Index: From<usize>,
+    {
+        self.vec
+            .iter_mut()
+            .enumerate()
+            .try_for_each(|(index, value)| f(index.into(), value))
+    }
+
+    /// Retains only the values for which `f` returns `Ok(true)`, like [`Self::retain`].
+    ///
+    /// If `f` returns an error, then it is not called on any further values, and the error is returned.
+    /// In this case, the values before the failing one are filtered as usual, while the failing value and all values after it are retained.
+    pub fn try_retain<Error>(
+        &mut self,
+        mut f: impl FnMut(&Value) -> Result<bool, Error>,
+    ) -> Result<(), Error> {
+        let mut error = None;
+        self.vec.retain(|value| {
+            if error.is_some() {
+                return true;
+            }
+            f(value).unwrap_or_else(|e| {
+                error = Some(e);
+                true
+            })
+        });
+        error.map_or(Ok(()), Err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TaggedVec;
+
+    #[test]
+    fn try_retain() {
+        let mut vec = TaggedVec::<usize, i32>::from(vec![1, 2, 3, -4, 5, 6]);
+        let mut visited = Vec::new();
+        assert_eq!(
+            vec.try_for_each_with_index(|index, value| {
+                visited.push(index);
+                if *value < 0 { Err(index) } else { Ok(()) }
+            }),
+            Err(3)
+        );
+        assert_eq!(visited, [0, 1, 2, 3]);
+
+        let result = vec.try_retain(|value| {
+            if *value < 0 {
+                Err("negative")
+            } else {
+                Ok(value % 2 == 1)
+            }
+        });
+        assert_eq!(result, Err("negative"));
+        assert_eq!(vec, [1, 3, -4, 5, 6]);
+
+        assert_eq!(
+            vec.try_for_each_with_index_mut(|_, value| {
+                *value = value.abs();
+                Ok::<_, ()>(())
+            }),
+            Ok(())
+        );
+        assert_eq!(vec.try_retain(|value| Ok::<_, ()>(*value > 3)), Ok(()));
+        assert_eq!(vec, [4, 5, 6]);
+    }
+}
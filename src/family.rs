@@ -0,0 +1,377 @@
+//! A `TaggedVec` with secondary `TaggedVec`s that are kept at the same length.
+
+use std::{
+    any::Any,
+    marker::PhantomData,
+    ops::Deref,
+    sync::atomic::{AtomicUsize, Ordering as AtomicOrdering},
+};
+
+use crate::{EntryIteratorMut, IndexIterator, TaggedVec};
+
+/// A primary [`TaggedVec`] together with secondary `TaggedVec`s of other value types that always have the same length.
+///
+/// All structural modifications go through the family and are applied to every member, so the secondary vectors cannot get out of sync with the primary one.
+/// Secondary vectors are registered with [`Self::register`], which returns a [`SecondaryHandle`] to access them.
+/// Read access to the primary `TaggedVec` is available via [`Deref`].
+pub struct TaggedFamily<Index, Value> {
+    id: usize,
+    primary: TaggedVec<Index, Value>,
+    secondaries: Vec<Box<dyn Secondary<Index>>>,
+}
+
+/// A typed handle to a secondary vector of a [`TaggedFamily`].
+pub struct SecondaryHandle<Value> {
+    family: usize,
+    position: usize,
+    value_type: PhantomData<fn() -> Value>,
+}
+
+/// A secondary vector together with the function creating its values for new indices.
+struct SecondaryVec<Index, Value> {
+    vec: TaggedVec<Index, Value>,
+    fill: Box<dyn FnMut(Index) -> Value>,
+}
+
+/// Truncates all secondary vectors back to `len` when dropped, to undo a partial [`TaggedFamily::push`] if a fill function panics.
+struct PushGuard<'a, Index> {
+    secondaries: &'a mut [Box<dyn Secondary<Index>>],
+    len: usize,
+}
+
+/// Type-erased structural operations on secondary vectors.
+trait Secondary<Index> {
+    fn len(&self) -> usize;
+
+    fn push_fill(&mut self, index: Index);
+
+    fn truncate(&mut self, len: usize);
+
+    fn remove(&mut self, index: usize);
+
+    fn swap_remove(&mut self, index: usize);
+
+    fn apply_remap(&mut self, remap: &TaggedVec<Index, Option<Index>>);
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<Index, Value> TaggedFamily<Index, Value> {
+    /// Creates a new family with the given primary vector and no secondary vectors.
+    pub fn new(primary: TaggedVec<Index, Value>) -> Self {
+        static NEXT_FAMILY_ID: AtomicUsize = AtomicUsize::new(0);
+
+        Self {
+            id: NEXT_FAMILY_ID.fetch_add(1, AtomicOrdering::Relaxed),
+            primary,
+            secondaries: Vec::new(),
+        }
+    }
+
+    /// Registers a new secondary vector.
+    ///
+    /// The secondary vector is filled by calling `fill` for each existing index, and `fill` is called again for each index that is pushed later.
+    pub fn register<SecondaryValue: 'static>(
+        &mut self,
+        mut fill: impl FnMut(Index) -> SecondaryValue + 'static,
+    ) -> SecondaryHandle<SecondaryValue>
+    where
+        Index: From<usize> + Into<usize> + Copy + 'static,
+    {
+        let vec = IndexIterator::new(0, self.primary.len())
+            .map(&mut fill)
+            .collect();
+        self.secondaries.push(Box::new(SecondaryVec {
+            vec,
+            fill: Box::new(fill),
+        }));
+        SecondaryHandle {
+            family: self.id,
+            position: self.secondaries.len() - 1,
+            value_type: PhantomData,
+        }
+    }
+
+    /// Returns the secondary vector of the given handle.
+    ///
+    /// Panics if the handle belongs to a different family.
+    pub fn secondary<SecondaryValue: 'static>(
+        &self,
+        handle: &SecondaryHandle<SecondaryValue>,
+    ) -> &TaggedVec<Index, SecondaryValue>
+    where
+        Index: 'static,
+    {
+        self.check_handle(handle);
+        &self.secondaries[handle.position]
+            .as_any()
+            .downcast_ref::<SecondaryVec<Index, SecondaryValue>>()
+            .expect("secondary handle has the wrong value type")
+            .vec
+    }
+
+    /// Returns a mutable reference to the value at the given index of the secondary vector of the given handle, or `None` if the index is out of bounds.
+    ///
+    /// Panics if the handle belongs to a different family.
+    pub fn secondary_get_mut<SecondaryValue: 'static>(
+        &mut self,
+        handle: &SecondaryHandle<SecondaryValue>,
+        index: Index,
+    ) -> Option<&mut SecondaryValue>
+    where
+        Index: Into<usize> + 'static,
+    {
+        self.secondary_vec_mut(handle).vec.vec.get_mut(index.into())
+    }
+
+    /// Returns an iterator over mutable references to the entries of the secondary vector of the given handle.
+    ///
+    /// Panics if the handle belongs to a different family.
+    pub fn secondary_iter_mut<SecondaryValue: 'static>(
+        &mut self,
+        handle: &SecondaryHandle<SecondaryValue>,
+    ) -> EntryIteratorMut<'_, Index, SecondaryValue>
+    where
+        Index: From<usize> + 'static,
+    {
+        EntryIteratorMut::new(&mut self.secondary_vec_mut(handle).vec.vec)
+    }
+
+    /// Returns a mutable reference to the value at the given index of the primary vector, or `None` if the index is out of bounds.
+    pub fn get_mut(&mut self, index: Index) -> Option<&mut Value>
+    where
+        Index: Into<usize>,
+    {
+        self.primary.vec.get_mut(index.into())
+    }
+
+    /// Inserts the given value at the back of the primary vector, and filled values at the back of all secondary vectors.
+    ///
+    /// The secondary values are filled before the primary value is pushed.
+    /// If a fill function panics, then the values already pushed to other secondary vectors are removed again, so the family stays in sync.
+    /// Returns the index of the new values.
+    pub fn push(&mut self, value: Value) -> Index
+    where
+        Index: From<usize> + Copy,
+    {
+        let len = self.primary.len();
+        let guard = PushGuard {
+            secondaries: &mut self.secondaries,
+            len,
+        };
+        for secondary in guard.secondaries.iter_mut() {
+            secondary.push_fill(len.into());
+        }
+        std::mem::forget(guard);
+        self.primary.push(value)
+    }
+
+    /// Removes the values at the given index from all vectors, shifting all values after it one position to the left.
+    ///
+    /// Returns the removed value of the primary vector.
+    /// Panics if the index is out of bounds, without modifying any vector.
+    pub fn remove(&mut self, index: Index) -> Value
+    where
+        Index: Into<usize>,
+    {
+        let index = index.into();
+        assert!(index < self.primary.len(), "index {index} out of bounds");
+        for secondary in &mut self.secondaries {
+            secondary.remove(index);
+        }
+        self.primary.vec.remove(index)
+    }
+
+    /// Removes the values at the given index from all vectors, replacing them with the values at the last index.
+    ///
+    /// Returns the removed value of the primary vector.
+    /// Panics if the index is out of bounds, without modifying any vector.
+    pub fn swap_remove(&mut self, index: Index) -> Value
+    where
+        Index: Into<usize>,
+    {
+        let index = index.into();
+        assert!(index < self.primary.len(), "index {index} out of bounds");
+        for secondary in &mut self.secondaries {
+            secondary.swap_remove(index);
+        }
+        self.primary.vec.swap_remove(index)
+    }
+
+    /// Retains only the indices whose primary value satisfies the predicate, removing the other indices from all vectors.
+    ///
+    /// Returns a remap from each old index to its new index, or `None` if it was removed.
+    pub fn retain_with_remap(
+        &mut self,
+        f: impl FnMut(&Value) -> bool,
+    ) -> TaggedVec<Index, Option<Index>>
+    where
+        Index: From<usize> + Into<usize> + Copy,
+    {
+        let remap = self.primary.retain_with_remap(f);
+        for secondary in &mut self.secondaries {
+            secondary.apply_remap(&remap);
+        }
+        remap
+    }
+
+    /// Consumes the family, returning the primary vector.
+    pub fn into_primary(self) -> TaggedVec<Index, Value> {
+        self.primary
+    }
+
+    /// Checks the internal consistency of the family, see [`crate::invariants`].
+    ///
+    /// Panics if a secondary vector has a different length than the primary one.
+    /// Does nothing in release builds.
+    pub fn debug_validate(&self) {
+        if cfg!(debug_assertions) {
+            for (position, secondary) in self.secondaries.iter().enumerate() {
+                assert_eq!(
+                    secondary.len(),
+                    self.primary.len(),
+                    "secondary vector {position} is out of sync with the primary vector"
+                );
+            }
+        }
+    }
+
+    fn check_handle<SecondaryValue>(&self, handle: &SecondaryHandle<SecondaryValue>) {
+        assert_eq!(
+            handle.family, self.id,
+            "secondary handle of a different family"
+        );
+    }
+
+    fn secondary_vec_mut<SecondaryValue: 'static>(
+        &mut self,
+        handle: &SecondaryHandle<SecondaryValue>,
+    ) -> &mut SecondaryVec<Index, SecondaryValue>
+    where
+        Index: 'static,
+    {
+        self.check_handle(handle);
+        self.secondaries[handle.position]
+            .as_any_mut()
+            .downcast_mut()
+            .expect("secondary handle has the wrong value type")
+    }
+}
+
+impl<Index: Into<usize> + Copy + 'static, Value: 'static> Secondary<Index>
+    for SecondaryVec<Index, Value>
+{
+    fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    fn push_fill(&mut self, index: Index) {
+        self.vec.vec.push((self.fill)(index));
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.vec.vec.truncate(len);
+    }
+
+    fn remove(&mut self, index: usize) {
+        self.vec.vec.remove(index);
+    }
+
+    fn swap_remove(&mut self, index: usize) {
+        self.vec.vec.swap_remove(index);
+    }
+
+    fn apply_remap(&mut self, remap: &TaggedVec<Index, Option<Index>>) {
+        self.vec.apply_remap(remap);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl<Index> Drop for PushGuard<'_, Index> {
+    fn drop(&mut self) {
+        for secondary in self.secondaries.iter_mut() {
+            secondary.truncate(self.len);
+        }
+    }
+}
+
+impl<Value> Clone for SecondaryHandle<Value> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Value> Copy for SecondaryHandle<Value> {}
+
+impl<Index, Value> Deref for TaggedFamily<Index, Value> {
+    type Target = TaggedVec<Index, Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.primary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{self, AssertUnwindSafe};
+
+    use super::TaggedFamily;
+    use crate::TaggedVec;
+
+    #[test]
+    fn family() {
+        let mut family = TaggedFamily::new(TaggedVec::<usize, _>::from(vec!["a", "b"]));
+        let lengths = family.register(|index| index * 10);
+        let flags = family.register(|_| false);
+
+        let c = family.push("c");
+        *family.secondary_get_mut(&flags, c).unwrap() = true;
+        assert_eq!(*family.secondary(&lengths), [0, 10, 20]);
+
+        assert_eq!(family.remove(0), "a");
+        assert_eq!(*family.secondary(&lengths), [10, 20]);
+        assert_eq!(*family.secondary(&flags), [false, true]);
+
+        let remap = family.retain_with_remap(|value| *value == "c");
+        assert_eq!(remap, [None, Some(0)]);
+        assert_eq!(*family, ["c"]);
+        assert_eq!(*family.secondary(&lengths), [20]);
+        assert_eq!(*family.secondary(&flags), [true]);
+    }
+
+    #[test]
+    fn panicking_fill() {
+        let mut family = TaggedFamily::new(TaggedVec::<usize, _>::from(vec!["a"]));
+        let lengths = family.register(|index| index * 10);
+        let flags = family.register(|index| {
+            assert!(index < 1, "no flag for index {index}");
+            false
+        });
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| family.push("b")));
+        assert!(result.is_err());
+        family.debug_validate();
+        assert_eq!(*family, ["a"]);
+        assert_eq!(*family.secondary(&lengths), [0]);
+        assert_eq!(*family.secondary(&flags), [false]);
+    }
+
+    #[test]
+    #[should_panic = "secondary handle of a different family"]
+    fn handle_of_different_family() {
+        let mut a = TaggedFamily::new(TaggedVec::<usize, _>::from(vec![1]));
+        let mut b = TaggedFamily::new(TaggedVec::<usize, _>::from(vec![1]));
+        let handle = a.register(|_| 0u8);
+        b.register(|_| 0u8);
+        b.secondary(&handle);
+    }
+}
@@ -0,0 +1,74 @@
+//! An immutable, cheaply shareable `TaggedVec`.
+
+use std::{ops::Deref, sync::Arc};
+
+use crate::TaggedVec;
+
+/// An immutable [`TaggedVec`], created by [`TaggedVec::freeze`].
+///
+/// Only read access is available, via [`Deref`].
+/// Cloning is cheap, since all clones share the same values, which allows to share the vector between threads.
+pub struct FrozenTaggedVec<Index, Value> {
+    vec: Arc<TaggedVec<Index, Value>>,
+}
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Converts the `TaggedVec` into a [`FrozenTaggedVec`] that cannot be mutated.
+    pub fn freeze(self) -> FrozenTaggedVec<Index, Value> {
+        FrozenTaggedVec {
+            vec: Arc::new(self),
+        }
+    }
+}
+
+impl<Index, Value> FrozenTaggedVec<Index, Value> {
+    /// Converts the `FrozenTaggedVec` back into a mutable `TaggedVec`.
+    ///
+    /// The values are cloned only if other clones of this `FrozenTaggedVec` exist.
+    pub fn thaw(self) -> TaggedVec<Index, Value>
+    where
+        Value: Clone,
+    {
+        Arc::unwrap_or_clone(self.vec)
+    }
+}
+
+impl<Index, Value> Clone for FrozenTaggedVec<Index, Value> {
+    fn clone(&self) -> Self {
+        Self {
+            vec: self.vec.clone(),
+        }
+    }
+}
+
+impl<Index, Value> Deref for FrozenTaggedVec<Index, Value> {
+    type Target = TaggedVec<Index, Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use crate::TaggedVec;
+
+    #[test]
+    fn freeze_and_thaw() {
+        let frozen = TaggedVec::<usize, _>::from(vec![1, 2, 3]).freeze();
+        let sum = thread::scope(|scope| {
+            let frozen = frozen.clone();
+            scope
+                .spawn(move || frozen.iter_values().sum::<i32>())
+                .join()
+                .unwrap()
+        });
+        assert_eq!(sum, 6);
+
+        let mut vec = frozen.thaw();
+        vec.push(4);
+        assert_eq!(vec, [1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,206 @@
+//! A `TaggedVec` wrapper that detects the use of indices invalidated by shifting operations.
+
+use std::{cmp::Ordering, ops::Deref};
+
+use crate::TaggedVec;
+
+/// An index stamped with the generation of the [`GenerationalTaggedVec`] it was issued by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StampedIndex<Index> {
+    index: Index,
+    generation: u64,
+}
+
+/// A [`TaggedVec`] that keeps a generation counter which is incremented by every operation that shifts values to different indices.
+///
+/// Indices are issued as [`StampedIndex`]es carrying the generation they were issued in.
+/// Accessing the vector with an index from an older generation panics in debug builds, which catches the use of invalidated indices.
+/// Read access to the underlying `TaggedVec` is available via [`Deref`].
+pub struct GenerationalTaggedVec<Index, Value> {
+    vec: TaggedVec<Index, Value>,
+    generation: u64,
+}
+
+impl<Index> StampedIndex<Index> {
+    /// Returns the index without its generation stamp.
+    pub fn index(self) -> Index {
+        self.index
+    }
+
+    /// Returns the generation this index was issued in.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+}
+
+impl<Index, Value> GenerationalTaggedVec<Index, Value> {
+    /// Creates a new empty `GenerationalTaggedVec`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the current generation.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Stamps the given index with the current generation.
+    pub fn stamp(&self, index: Index) -> StampedIndex<Index> {
+        StampedIndex {
+            index,
+            generation: self.generation,
+        }
+    }
+
+    /// Inserts the given value at the back of the vector, returning its stamped index.
+    ///
+    /// This does not shift any values, so the generation is unchanged.
+    pub fn push(&mut self, value: Value) -> StampedIndex<Index>
+    where
+        Index: From<usize>,
+    {
+        let index = self.vec.push(value);
+        self.stamp(index)
+    }
+
+    /// Returns a reference to the value at the given index, or `None` if the index is out of bounds.
+    ///
+    /// Panics in debug builds if the index is from an older generation.
+    pub fn get(&self, index: StampedIndex<Index>) -> Option<&Value>
+    where
+        Index: Into<usize>,
+    {
+        self.check_generation(&index);
+        self.vec.get(index.index)
+    }
+
+    /// Returns a mutable reference to the value at the given index, or `None` if the index is out of bounds.
+    ///
+    /// Panics in debug builds if the index is from an older generation.
+    pub fn get_mut(&mut self, index: StampedIndex<Index>) -> Option<&mut Value>
+    where
+        Index: Into<usize>,
+    {
+        self.check_generation(&index);
+        self.vec.vec.get_mut(index.index.into())
+    }
+
+    /// Inserts the given `value` at position `index`, shifting all existing values in range `index..` one position to the right.
+    ///
+    /// This starts a new generation.
+    /// Panics in debug builds if the index is from an older generation.
+    pub fn insert(&mut self, index: StampedIndex<Index>, value: Value)
+    where
+        Index: Into<usize>,
+    {
+        self.check_generation(&index);
+        self.vec.insert(index.index, value);
+        self.generation += 1;
+    }
+
+    /// Removes and returns the value at position `index`, shifting all values in range `index + 1..` one position to the left.
+    ///
+    /// This starts a new generation.
+    /// Panics in debug builds if the index is from an older generation.
+    pub fn remove(&mut self, index: StampedIndex<Index>) -> Value
+    where
+        Index: Into<usize>,
+    {
+        self.check_generation(&index);
+        let value = self.vec.vec.remove(index.index.into());
+        self.generation += 1;
+        value
+    }
+
+    /// Retains only the values specified by the predicate, see [`TaggedVec::retain`].
+    ///
+    /// This starts a new generation.
+    pub fn retain(&mut self, f: impl FnMut(&Value) -> bool) {
+        self.vec.retain(f);
+        self.generation += 1;
+    }
+
+    /// Sorts the values with the given comparator, see [`slice::sort_by`].
+    ///
+    /// This starts a new generation.
+    pub fn sort_by(&mut self, compare: impl FnMut(&Value, &Value) -> Ordering) {
+        self.vec.vec.sort_by(compare);
+        self.generation += 1;
+    }
+
+    /// Consumes the `GenerationalTaggedVec`, returning the underlying `TaggedVec`.
+    pub fn into_inner(self) -> TaggedVec<Index, Value> {
+        self.vec
+    }
+
+    fn check_generation(&self, index: &StampedIndex<Index>) {
+        debug_assert_eq!(
+            index.generation, self.generation,
+            "index from generation {} used in generation {}",
+            index.generation, self.generation
+        );
+    }
+}
+
+impl<Index, Value> Default for GenerationalTaggedVec<Index, Value> {
+    fn default() -> Self {
+        Self {
+            vec: Default::default(),
+            generation: 0,
+        }
+    }
+}
+
+impl<Index, Value> Deref for GenerationalTaggedVec<Index, Value> {
+    type Target = TaggedVec<Index, Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+impl<Index: Into<usize>, Value> std::ops::Index<StampedIndex<Index>>
+    for GenerationalTaggedVec<Index, Value>
+{
+    type Output = Value;
+
+    fn index(&self, index: StampedIndex<Index>) -> &Self::Output {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<Index: Into<usize>, Value> std::ops::IndexMut<StampedIndex<Index>>
+    for GenerationalTaggedVec<Index, Value>
+{
+    fn index_mut(&mut self, index: StampedIndex<Index>) -> &mut Self::Output {
+        self.get_mut(index).expect("index out of bounds")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GenerationalTaggedVec;
+
+    #[test]
+    fn generations() {
+        let mut vec = GenerationalTaggedVec::<usize, _>::new();
+        let a = vec.push('a');
+        let b = vec.push('b');
+        assert_eq!(vec[b], 'b');
+
+        vec.insert(a, 'c');
+        assert_eq!(vec.generation(), 1);
+        assert_eq!(vec[vec.stamp(2)], 'b');
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "index from generation 0 used in generation 1"]
+    fn stale_index() {
+        let mut vec = GenerationalTaggedVec::<usize, _>::new();
+        let a = vec.push('a');
+        vec.remove(vec.stamp(0));
+        vec.push('b');
+        let _ = vec[a];
+    }
+}
@@ -0,0 +1,132 @@
+//! Allocation of typed indices independent of any `TaggedVec`.
+
+use std::marker::PhantomData;
+
+use crate::{IndexIterator, TaggedBitSet};
+
+/// Hands out indices of type `Index`, recycling freed ones.
+///
+/// This allows multiple `TaggedVec`s to share a single index space, where each of them is sized to [`Self::index_bound`].
+/// Freed indices are reused in last-in first-out order before new indices are created.
+pub struct IndexAllocator<Index> {
+    /// The number of indices ever created.
+    index_bound: usize,
+    free_list: Vec<usize>,
+    free_set: TaggedBitSet<usize>,
+    index_type: PhantomData<Index>,
+}
+
+impl<Index> IndexAllocator<Index> {
+    /// Creates a new `IndexAllocator` that has not handed out any indices yet.
+    pub fn new() -> Self {
+        Self {
+            index_bound: 0,
+            free_list: Vec::new(),
+            free_set: TaggedBitSet::new(),
+            index_type: PhantomData,
+        }
+    }
+
+    /// Returns the number of currently allocated indices.
+    pub fn len(&self) -> usize {
+        self.index_bound - self.free_list.len()
+    }
+
+    /// Returns `true` if no indices are currently allocated.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns one more than the largest index ever allocated, or zero if no index was allocated yet.
+    ///
+    /// All allocated indices are smaller than this bound.
+    pub fn index_bound(&self) -> usize {
+        self.index_bound
+    }
+
+    /// Allocates an index, reusing the most recently freed index if there is one.
+    pub fn alloc(&mut self) -> Index
+    where
+        Index: From<usize>,
+    {
+        if let Some(index) = self.free_list.pop() {
+            self.free_set.remove(index);
+            index.into()
+        } else {
+            self.index_bound += 1;
+            (self.index_bound - 1).into()
+        }
+    }
+
+    /// Frees the given index, such that it can be reused by a later call to [`Self::alloc`].
+    ///
+    /// Panics if the index is not allocated.
+    pub fn free(&mut self, index: Index)
+    where
+        Index: Into<usize>,
+    {
+        let index = index.into();
+        assert!(
+            index < self.index_bound && self.free_set.insert(index),
+            "index {index} is not allocated"
+        );
+        self.free_list.push(index);
+    }
+
+    /// Returns `true` if the given index is currently allocated.
+    pub fn is_allocated(&self, index: Index) -> bool
+    where
+        Index: Into<usize>,
+    {
+        let index = index.into();
+        index < self.index_bound && !self.free_set.contains(index)
+    }
+
+    /// Returns an iterator over the currently allocated indices in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = Index>
+    where
+        Index: From<usize>,
+    {
+        IndexIterator::<usize>::new(0, self.index_bound)
+            .filter(|index| !self.free_set.contains(*index))
+            .map(Index::from)
+    }
+}
+
+impl<Index> Default for IndexAllocator<Index> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IndexAllocator;
+
+    #[test]
+    fn alloc_and_free() {
+        let mut allocator = IndexAllocator::<usize>::new();
+        assert_eq!(
+            [allocator.alloc(), allocator.alloc(), allocator.alloc()],
+            [0, 1, 2]
+        );
+        allocator.free(1);
+        allocator.free(0);
+        assert!(!allocator.is_allocated(0) && allocator.is_allocated(2));
+        assert_eq!(allocator.iter().collect::<Vec<_>>(), [2]);
+        assert_eq!(
+            [allocator.alloc(), allocator.alloc(), allocator.alloc()],
+            [0, 1, 3]
+        );
+        assert_eq!((allocator.len(), allocator.index_bound()), (4, 4));
+    }
+
+    #[test]
+    #[should_panic = "index 0 is not allocated"]
+    fn double_free_panics() {
+        let mut allocator = IndexAllocator::<usize>::new();
+        let index = allocator.alloc();
+        allocator.free(index);
+        allocator.free(index);
+    }
+}
@@ -0,0 +1,70 @@
+//! Arithmetic on index types via their `usize` representation.
+
+/// Arithmetic on index types, implemented for all types that convert from and into [`usize`].
+///
+/// This allows to step through indices without converting to `usize` and back at every operation.
+pub trait IndexArithmetic: Sized {
+    /// Returns the index `delta` positions after this index, or `None` if the result would be negative or overflow.
+    fn offset(self, delta: isize) -> Option<Self>;
+
+    /// Returns the absolute number of positions between this index and `other`.
+    fn distance_to(self, other: Self) -> usize;
+
+    /// Returns the next index.
+    ///
+    /// Panics on overflow.
+    fn succ(self) -> Self;
+
+    /// Returns the previous index.
+    ///
+    /// Panics if this index is zero.
+    fn pred(self) -> Self;
+}
+
+impl<Index: From<usize> + Into<usize> + Copy> IndexArithmetic for Index {
+    fn offset(self, delta: isize) -> Option<Self> {
+        self.into().checked_add_signed(delta).map(Self::from)
+    }
+
+    fn distance_to(self, other: Self) -> usize {
+        self.into().abs_diff(other.into())
+    }
+
+    fn succ(self) -> Self {
+        Self::from(self.into().checked_add(1).expect("index overflow"))
+    }
+
+    fn pred(self) -> Self {
+        Self::from(self.into().checked_sub(1).expect("index underflow"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IndexArithmetic;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Index(usize);
+
+    impl From<usize> for Index {
+        fn from(value: usize) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<Index> for usize {
+        fn from(value: Index) -> Self {
+            value.0
+        }
+    }
+
+    #[test]
+    fn index_arithmetic() {
+        assert_eq!(Index(3).offset(-2), Some(Index(1)));
+        assert_eq!(Index(3).offset(-4), None);
+        assert_eq!(Index(3).distance_to(Index(7)), 4);
+        assert_eq!(Index(7).distance_to(Index(3)), 4);
+        assert_eq!(Index(3).succ(), Index(4));
+        assert_eq!(Index(3).pred(), Index(2));
+    }
+}
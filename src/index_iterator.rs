@@ -32,6 +32,14 @@ where
         }
     }
 
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.start_inclusive = self
+            .start_inclusive
+            .saturating_add(n)
+            .min(self.end_exclusive);
+        self.next()
+    }
+
     fn size_hint(&self) -> (usize, Option<usize>) {
         let len = self.end_exclusive - self.start_inclusive;
         (len, Some(len))
@@ -50,6 +58,14 @@ where
             None
         }
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.end_exclusive = self
+            .end_exclusive
+            .saturating_sub(n)
+            .max(self.start_inclusive);
+        self.next_back()
+    }
 }
 
 impl<Index> ExactSizeIterator for IndexIterator<Index> where Index: From<usize> {}
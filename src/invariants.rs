@@ -0,0 +1,67 @@
+//! Consistency checks for use in tests, including property tests of downstream crates.
+//!
+//! The `debug_validate` methods, like [`TaggedFamily::debug_validate`](crate::TaggedFamily::debug_validate), check the internal consistency of a container.
+//! They panic if an invariant is violated, and do nothing in release builds.
+//! [`TaggedVec::debug_validate`] is a no-op, since `TaggedVec` has no invariants of its own.
+
+use crate::TaggedVec;
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Does nothing, since a `TaggedVec` has no invariants beyond those that [`Vec`] already guarantees.
+    ///
+    /// This hook exists for API symmetry with [`TaggedFamily::debug_validate`](crate::TaggedFamily::debug_validate), so generic test code can validate any container.
+    pub fn debug_validate(&self) {}
+}
+
+/// Asserts that the given indices form exactly the range `0..n` in any order.
+///
+/// Panics with the smallest duplicate index if there is any, and otherwise with the smallest missing index.
+pub fn assert_dense_indices<Index: Into<usize>>(indices: impl IntoIterator<Item = Index>) {
+    let indices: Vec<usize> = indices.into_iter().map(Into::into).collect();
+    let mut counts = vec![0usize; indices.len()];
+    for index in indices {
+        // Out-of-range indices imply a missing index.
+        if let Some(count) = counts.get_mut(index) {
+            *count += 1;
+        }
+    }
+
+    if let Some(duplicate) = counts.iter().position(|count| *count > 1) {
+        panic!("duplicate index {duplicate}");
+    }
+    if let Some(missing) = counts.iter().position(|count| *count == 0) {
+        panic!("missing index {missing}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::assert_dense_indices;
+    use crate::{TaggedFamily, TaggedVec};
+
+    #[test]
+    fn debug_validate() {
+        let mut family = TaggedFamily::new(TaggedVec::<usize, _>::from(vec![1, 2, 3]));
+        family.register(|index| index * 10);
+        family.swap_remove(0);
+        family.debug_validate();
+    }
+
+    #[test]
+    fn dense_indices() {
+        assert_dense_indices::<usize>([2, 0, 1]);
+        assert_dense_indices::<usize>([]);
+    }
+
+    #[test]
+    #[should_panic = "duplicate index 1"]
+    fn dense_indices_duplicate() {
+        assert_dense_indices::<usize>([1, 0, 1, 5]);
+    }
+
+    #[test]
+    #[should_panic = "missing index 2"]
+    fn dense_indices_missing() {
+        assert_dense_indices::<usize>([0, 1, 3]);
+    }
+}
@@ -0,0 +1,35 @@
+//! Extension methods on iterators for producing tagged data.
+
+use crate::TaggedVec;
+
+/// Extension methods for producing tagged data from iterators.
+pub trait TaggedIteratorExt: Iterator + Sized {
+    /// Collects the items into a [`TaggedVec`] indexed by `Index`.
+    fn collect_tagged<Index>(self) -> TaggedVec<Index, Self::Item> {
+        self.collect()
+    }
+
+    /// Like [`Iterator::enumerate`], but yields the positions as `Index`.
+    fn enumerate_tagged<Index: From<usize>>(self) -> impl Iterator<Item = (Index, Self::Item)> {
+        self.enumerate().map(|(index, item)| (index.into(), item))
+    }
+}
+
+impl<I: Iterator> TaggedIteratorExt for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::TaggedIteratorExt;
+    use crate::TaggedVec;
+
+    #[test]
+    fn collect_and_enumerate_tagged() {
+        let vec = (1..4).map(|value| value * 2).collect_tagged::<usize>();
+        assert_eq!(vec, TaggedVec::from(vec![2, 4, 6]));
+
+        assert_eq!(
+            "ab".chars().enumerate_tagged::<usize>().collect::<Vec<_>>(),
+            [(0, 'a'), (1, 'b')]
+        );
+    }
+}
@@ -0,0 +1,157 @@
+//! Joint iteration over a `TaggedVec` and multiple sparse containers sharing the same index type.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::{BuildHasher, Hash},
+};
+
+use crate::{EntryIterator, TaggedVec};
+
+/// A container that may store a value for an index.
+pub trait TaggedLookup<Index> {
+    /// The type of the stored values.
+    type Value;
+
+    /// Returns a reference to the value stored for the given index, or `None` if there is none.
+    fn lookup(&self, index: Index) -> Option<&Self::Value>;
+}
+
+/// A view of a `TaggedVec` of optional values that treats `None` values as missing, created by [`TaggedVec::as_sparse`].
+pub struct SparseTaggedVec<'a, Index, Value> {
+    vec: &'a TaggedVec<Index, Option<Value>>,
+}
+
+/// A tuple of [`TaggedLookup`]s that can be joined with a `TaggedVec` via [`TaggedVec::join`].
+pub trait JoinParticipants<'a, Index, Primary: 'a> {
+    /// The joined entry, consisting of the index, the primary value, and the values of all participants.
+    type Item;
+
+    /// Returns the joined entry for the given index and primary value, or `None` if any participant has no value for the index.
+    fn join_entry(&self, index: Index, primary: &'a Primary) -> Option<Self::Item>;
+}
+
+macro_rules! impl_join_participants {
+    ($($participant:ident),*) => {
+        impl<'a, Index: Copy, Primary: 'a, $($participant: TaggedLookup<Index, Value: 'a> + 'a),*>
+            JoinParticipants<'a, Index, Primary> for ($(&'a $participant,)*)
+        {
+            type Item = (Index, &'a Primary, $(&'a $participant::Value,)*);
+
+            #[allow(non_snake_case)]
+            fn join_entry(&self, index: Index, primary: &'a Primary) -> Option<Self::Item> {
+                let ($($participant,)*) = *self;
+                Some((index, primary, $($participant.lookup(index)?,)*))
+            }
+        }
+    };
+}
+
+impl_join_participants!(A);
+impl_join_participants!(A, B);
+impl_join_participants!(A, B, C);
+impl_join_participants!(A, B, C, D);
+impl_join_participants!(A, B, C, D, E);
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Returns an iterator over the entries of `self` joined with the values of the given participants at the same indices.
+    ///
+    /// The participants are given as a tuple of references to [`TaggedLookup`]s, and indices missing from any participant are skipped.
+    /// See also the [`join!`](crate::join!) macro.
+    pub fn join<'a, Participants: JoinParticipants<'a, Index, Value>>(
+        &'a self,
+        participants: Participants,
+    ) -> impl Iterator<Item = Participants::Item>
+    where
+        Index: From<usize>,
+    {
+        EntryIterator::new(&self.vec)
+            .filter_map(move |(index, value)| participants.join_entry(index, value))
+    }
+}
+
+impl<Index, Value> TaggedVec<Index, Option<Value>> {
+    /// Returns a [`TaggedLookup`] view of this vector in which `None` values are missing.
+    pub fn as_sparse(&self) -> SparseTaggedVec<'_, Index, Value> {
+        SparseTaggedVec { vec: self }
+    }
+}
+
+/// Joins a `TaggedVec` with multiple [`TaggedLookup`]s, see [`TaggedVec::join`].
+///
+/// `join!(primary, a, b)` is equivalent to `primary.join((&a, &b))`.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use tagged_vec::{join, TaggedVec};
+///
+/// let names = TaggedVec::<usize, _>::from(vec!["a", "b", "c"]);
+/// let ages = HashMap::from([(0, 30), (2, 40)]);
+/// let heights = TaggedVec::<usize, _>::from(vec![Some(1.8), Some(1.6), None]);
+/// let heights = heights.as_sparse();
+///
+/// let joined: Vec<_> = join!(names, ages, heights).collect();
+/// assert_eq!(joined, [(0, &"a", &30, &1.8)]);
+/// ```
+#[macro_export]
+macro_rules! join {
+    ($primary:expr $(, $participant:expr)+ $(,)?) => {
+        $primary.join(($(&$participant,)+))
+    };
+}
+
+impl<Index: Into<usize>, Value> TaggedLookup<Index> for TaggedVec<Index, Value> {
+    type Value = Value;
+
+    fn lookup(&self, index: Index) -> Option<&Self::Value> {
+        self.get(index)
+    }
+}
+
+impl<Index: Into<usize>, Value> TaggedLookup<Index> for SparseTaggedVec<'_, Index, Value> {
+    type Value = Value;
+
+    fn lookup(&self, index: Index) -> Option<&Self::Value> {
+        self.vec.get(index)?.as_ref()
+    }
+}
+
+impl<Index: Hash + Eq, Value, S: BuildHasher> TaggedLookup<Index> for HashMap<Index, Value, S> {
+    type Value = Value;
+
+    fn lookup(&self, index: Index) -> Option<&Self::Value> {
+        self.get(&index)
+    }
+}
+
+impl<Index: Ord, Value> TaggedLookup<Index> for BTreeMap<Index, Value> {
+    type Value = Value;
+
+    fn lookup(&self, index: Index) -> Option<&Self::Value> {
+        self.get(&index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::TaggedVec;
+
+    #[test]
+    fn join() {
+        let primary = TaggedVec::<usize, _>::from(vec!['a', 'b', 'c', 'd']);
+        let dense = TaggedVec::<usize, _>::from(vec![1, 2, 3]);
+        let sparse = TaggedVec::<usize, _>::from(vec![None, Some("y"), Some("z"), Some("w")]);
+        let map = BTreeMap::from([(1, true), (2, false), (3, true)]);
+
+        assert_eq!(
+            primary
+                .join((&dense, &sparse.as_sparse(), &map))
+                .collect::<Vec<_>>(),
+            [(1, &'b', &2, &"y", &true), (2, &'c', &3, &"z", &false)]
+        );
+        assert_eq!(join!(primary, map).count(), 3);
+    }
+}
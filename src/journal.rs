@@ -0,0 +1,241 @@
+//! A `TaggedVec` wrapper that records mutations into an undo log.
+
+use std::ops::Deref;
+
+use crate::TaggedVec;
+
+/// A [`TaggedVec`] that records all mutations into an undo journal.
+///
+/// The state at any point can be captured with [`Self::snapshot`] and restored with [`Self::rollback_to`].
+/// Single mutations can be reverted with [`Self::undo`].
+/// Read access to the underlying `TaggedVec` is available via [`Deref`].
+pub struct JournaledTaggedVec<Index, Value> {
+    vec: TaggedVec<Index, Value>,
+    /// The journal entries, each with a sequence number that is never reused.
+    journal: Vec<(u64, JournalEntry<Value>)>,
+    /// The sequence number identifying the empty journal since the last [`Self::clear_journal`].
+    base_sequence: u64,
+    next_sequence: u64,
+}
+
+/// A point in the history of a [`JournaledTaggedVec`] that can be restored with [`JournaledTaggedVec::rollback_to`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JournalSnapshot {
+    /// The length of the journal.
+    len: usize,
+    /// The sequence number of the last journal entry, or the base sequence number if the journal was empty.
+    sequence: u64,
+}
+
+enum JournalEntry<Value> {
+    Push,
+    Insert(usize),
+    Remove(usize, Value),
+    Set(usize, Value),
+}
+
+impl<Index, Value> JournaledTaggedVec<Index, Value> {
+    /// Creates a new empty `JournaledTaggedVec`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts the given value at the back of the vector, returning its index.
+    pub fn push(&mut self, value: Value) -> Index
+    where
+        Index: From<usize>,
+    {
+        self.record(JournalEntry::Push);
+        self.vec.push(value)
+    }
+
+    /// Inserts the given `value` at position `index`, shifting all existing values in range `index..` one position to the right.
+    pub fn insert(&mut self, index: Index, value: Value)
+    where
+        Index: Into<usize>,
+    {
+        let index = index.into();
+        self.vec.vec.insert(index, value);
+        self.record(JournalEntry::Insert(index));
+    }
+
+    /// Removes and returns the value at position `index`, shifting all values in range `index + 1..` one position to the left.
+    pub fn remove(&mut self, index: Index) -> Value
+    where
+        Index: Into<usize>,
+        Value: Clone,
+    {
+        let index = index.into();
+        let value = self.vec.vec.remove(index);
+        self.record(JournalEntry::Remove(index, value.clone()));
+        value
+    }
+
+    /// Replaces the value at position `index` with the given `value`, returning the old value.
+    pub fn set(&mut self, index: Index, value: Value) -> Value
+    where
+        Index: Into<usize>,
+        Value: Clone,
+    {
+        let index = index.into();
+        let old_value = std::mem::replace(&mut self.vec.vec[index], value);
+        self.record(JournalEntry::Set(index, old_value.clone()));
+        old_value
+    }
+
+    /// Returns a snapshot of the current state that can later be restored with [`Self::rollback_to`].
+    pub fn snapshot(&self) -> JournalSnapshot {
+        JournalSnapshot {
+            len: self.journal.len(),
+            sequence: self.last_sequence(),
+        }
+    }
+
+    /// Reverts the most recent mutation.
+    ///
+    /// Returns `false` if the journal is empty.
+    pub fn undo(&mut self) -> bool {
+        let Some((_, entry)) = self.journal.pop() else {
+            return false;
+        };
+
+        match entry {
+            JournalEntry::Push => {
+                self.vec.vec.pop();
+            }
+            JournalEntry::Insert(index) => {
+                self.vec.vec.remove(index);
+            }
+            JournalEntry::Remove(index, value) => self.vec.vec.insert(index, value),
+            JournalEntry::Set(index, value) => self.vec.vec[index] = value,
+        }
+
+        true
+    }
+
+    /// Reverts all mutations made after the given snapshot was taken.
+    ///
+    /// Panics if the state of the snapshot was already reverted by an earlier call to [`Self::undo`] or [`Self::rollback_to`], or discarded by [`Self::clear_journal`].
+    pub fn rollback_to(&mut self, snapshot: JournalSnapshot) {
+        let sequence = match snapshot.len.checked_sub(1) {
+            Some(last) => self.journal.get(last).map(|(sequence, _)| *sequence),
+            None => Some(self.base_sequence),
+        };
+        assert_eq!(
+            sequence,
+            Some(snapshot.sequence),
+            "snapshot is no longer part of the journal"
+        );
+
+        while self.journal.len() > snapshot.len {
+            self.undo();
+        }
+    }
+
+    /// Discards the journal, making all mutations so far permanent.
+    ///
+    /// All previously taken snapshots become invalid.
+    pub fn clear_journal(&mut self) {
+        self.journal.clear();
+        self.base_sequence = self.next_sequence;
+        self.next_sequence += 1;
+    }
+
+    /// Consumes the `JournaledTaggedVec`, returning the underlying `TaggedVec`.
+    pub fn into_inner(self) -> TaggedVec<Index, Value> {
+        self.vec
+    }
+
+    fn record(&mut self, entry: JournalEntry<Value>) {
+        self.journal.push((self.next_sequence, entry));
+        self.next_sequence += 1;
+    }
+
+    fn last_sequence(&self) -> u64 {
+        self.journal
+            .last()
+            .map_or(self.base_sequence, |(sequence, _)| *sequence)
+    }
+}
+
+impl<Index, Value> Default for JournaledTaggedVec<Index, Value> {
+    fn default() -> Self {
+        Self::from(TaggedVec::new())
+    }
+}
+
+impl<Index, Value> From<TaggedVec<Index, Value>> for JournaledTaggedVec<Index, Value> {
+    fn from(value: TaggedVec<Index, Value>) -> Self {
+        Self {
+            vec: value,
+            journal: Default::default(),
+            base_sequence: 0,
+            next_sequence: 1,
+        }
+    }
+}
+
+impl<Index, Value> Deref for JournaledTaggedVec<Index, Value> {
+    type Target = TaggedVec<Index, Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JournaledTaggedVec;
+
+    #[test]
+    fn undo_and_rollback() {
+        let mut vec = JournaledTaggedVec::<usize, i32>::new();
+        vec.push(1);
+        vec.push(2);
+        let snapshot = vec.snapshot();
+
+        vec.insert(0, 3);
+        vec.set(1, 4);
+        vec.remove(2);
+        assert_eq!(vec.as_untagged_slice(), &[3, 4]);
+
+        assert!(vec.undo());
+        assert_eq!(vec.as_untagged_slice(), &[3, 4, 2]);
+
+        vec.push(5);
+        vec.rollback_to(snapshot);
+        assert_eq!(vec.as_untagged_slice(), &[1, 2]);
+
+        vec.rollback_to(vec.snapshot());
+        assert!(vec.undo());
+        assert!(vec.undo());
+        assert!(!vec.undo());
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    #[should_panic = "snapshot is no longer part of the journal"]
+    fn rollback_to_undone_snapshot() {
+        let mut vec = JournaledTaggedVec::<usize, i32>::new();
+        vec.push(1);
+        vec.push(2);
+        vec.push(3);
+        let snapshot = vec.snapshot();
+
+        vec.undo();
+        vec.undo();
+        vec.push(4);
+        vec.push(5);
+        vec.rollback_to(snapshot);
+    }
+
+    #[test]
+    #[should_panic = "snapshot is no longer part of the journal"]
+    fn rollback_to_cleared_snapshot() {
+        let mut vec = JournaledTaggedVec::<usize, i32>::new();
+        let snapshot = vec.snapshot();
+        vec.push(1);
+        vec.clear_journal();
+        vec.rollback_to(snapshot);
+    }
+}
@@ -11,28 +11,164 @@ use std::{
 
 use mapped_range_bounds::MappedRangeBounds;
 
+pub use crate::append_only::AppendOnlyTaggedVec;
+#[cfg(feature = "binary-io")]
+pub use crate::archive::{TaggedArchiveReader, TaggedArchiveWriter};
+pub use crate::auto_shrink::AutoShrinkTaggedVec;
+pub use crate::bit_set::{TaggedBitSet, TaggedBitSetIter};
+pub use crate::bounded::BoundedTaggedVec;
+pub use crate::builder::TaggedVecBuilder;
+pub use crate::bulk::DuplicateIndexPolicy;
+#[cfg(feature = "bumpalo")]
+pub use crate::bump::TaggedBumpVec;
+pub use crate::cow::CowTaggedVec;
+pub use crate::cursor::CursorMut;
+pub use crate::density::{IndexDensityError, MissingIndices};
+pub use crate::diff::Edit;
+pub use crate::entry_iterator::{EntryIterator, EntryIteratorMut};
+pub use crate::family::{SecondaryHandle, TaggedFamily};
+pub use crate::frozen::FrozenTaggedVec;
+#[cfg(feature = "generations")]
+pub use crate::generational::{GenerationalTaggedVec, StampedIndex};
+pub use crate::index_allocator::IndexAllocator;
+pub use crate::index_arithmetic::IndexArithmetic;
 pub use crate::index_iterator::IndexIterator;
-
+pub use crate::iterator_ext::TaggedIteratorExt;
+pub use crate::join::{JoinParticipants, SparseTaggedVec, TaggedLookup};
+pub use crate::journal::{JournalSnapshot, JournaledTaggedVec};
+pub use crate::mutation_batch::MutationBatch;
+pub use crate::nested::TaggedNestedVec;
+#[cfg(feature = "npy")]
+pub use crate::npy::NpyElement;
+pub use crate::observer::{ObservedTaggedVec, TaggedVecObserver};
+pub use crate::partition::Either;
+#[cfg(feature = "binary-io")]
+pub use crate::persistent_file::PersistentFileTaggedVec;
+#[cfg(feature = "binary-io")]
+pub use crate::pod::{Pod, SwapBytes};
+pub use crate::radix_sort::RadixKey;
+pub use crate::read_only::TaggedReadOnly;
+pub use crate::remap::MovedFrom;
+pub use crate::rle::RleTaggedVec;
+#[cfg(feature = "serde")]
+pub use crate::serde_impls::TaggedVecAppendSeed;
+pub use crate::shared::SharedTaggedVec;
+pub use crate::slice::TaggedSlice;
+#[cfg(feature = "binary-io")]
+pub use crate::slice_view::TaggedSliceView;
+pub use crate::sliding_window::SlidingWindow;
+pub use crate::sort::{SortKeys, SortOrder};
+pub use crate::tagged_range::TaggedRange;
+pub use crate::translation::IndexTranslation;
+pub use crate::view::{MappedTaggedVecView, TaggedVecView};
+pub use crate::windows::WindowsMut;
+#[cfg(feature = "derive")]
+pub use tagged_vec_derive::TaggedIndex;
+
+mod append_only;
+#[cfg(feature = "binary-io")]
+mod archive;
+mod auto_shrink;
 #[cfg(feature = "binary-io")]
 mod binary_io;
+mod bit_set;
+mod bounded;
+mod builder;
+mod bulk;
+#[cfg(feature = "bumpalo")]
+mod bump;
+mod cow;
+mod cursor;
+mod density;
+mod diff;
+mod duplicates;
+mod entry_iterator;
+#[cfg(feature = "binary-io")]
+mod external_sort;
+mod fallible;
+mod family;
+mod frozen;
+#[cfg(feature = "generations")]
+mod generational;
+mod index_allocator;
+mod index_arithmetic;
 mod index_iterator;
+pub mod invariants;
+mod iterator_ext;
+mod join;
+mod journal;
+mod map_api;
 mod mapped_range_bounds;
+mod masked;
+mod merge;
+mod mutation_batch;
+mod nested;
+#[cfg(feature = "npy")]
+mod npy;
+mod observer;
+#[cfg(feature = "rayon")]
+mod par_fn;
+mod parallel;
+mod partition;
+#[cfg(feature = "binary-io")]
+mod persistent_file;
+#[cfg(feature = "binary-io")]
+mod pod;
+#[cfg(feature = "binary-io")]
+mod portable_io;
+mod query;
+mod radix_sort;
+mod read_only;
+mod remap;
+mod rle;
+mod runs;
+mod scan;
+#[cfg(all(feature = "serde", feature = "binary-io"))]
+pub mod serde_compact;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod shared;
+mod slice;
+#[cfg(feature = "binary-io")]
+mod slice_view;
+mod sliding_window;
+mod sort;
+mod split;
+#[cfg(feature = "num-traits")]
+mod stats;
+mod table;
+mod tagged_range;
 #[cfg(test)]
 mod tests;
+#[cfg(feature = "binary-io")]
+mod text_encoding;
 mod trait_impls;
+mod translation;
+mod view;
+mod windows;
+mod zip;
 
 /// A [`Vec`] wrapper that allows indexing only via the given `Index` type.
 ///
 /// For actual operation, `Index` must implement [`From<usize>`] and [`Into<usize>`].
+///
+/// A `TaggedVec<Index, Value>` is guaranteed to have the same memory layout as a `Vec<Value>`.
+#[repr(transparent)]
 pub struct TaggedVec<Index, Value> {
     index_type: PhantomData<Index>,
     vec: Vec<Value>,
 }
 
 impl<Index, Value> TaggedVec<Index, Value> {
+    /// An empty `TaggedVec`, usable in constant expressions.
+    pub const EMPTY: Self = Self::new();
+
     /// Creates a new empty `TaggedVec`.
-    pub fn new() -> Self {
-        Self::default()
+    pub const fn new() -> Self {
+        Self {
+            index_type: PhantomData,
+            vec: Vec::new(),
+        }
     }
 
     /// Creates a new empty `TaggedVec` with at least the specified capacity.
@@ -43,6 +179,45 @@ impl<Index, Value> TaggedVec<Index, Value> {
         }
     }
 
+    /// Creates a `TaggedVec` of length `len` from the given entries, filling all slots without an entry with [`Default::default`].
+    ///
+    /// If an index occurs more than once, the last entry wins.
+    /// Panics if an index is out of bounds.
+    pub fn from_sparse_iter(iter: impl IntoIterator<Item = (Index, Value)>, len: usize) -> Self
+    where
+        Index: Into<usize>,
+        Value: Default,
+    {
+        let mut vec = Vec::with_capacity(len);
+        vec.resize_with(len, Value::default);
+        for (index, value) in iter {
+            vec[index.into()] = value;
+        }
+        vec.into()
+    }
+
+    /// Like [`Self::from_sparse_iter`], but fills all slots without an entry with the result of calling `fill` with their index.
+    pub fn from_sparse_iter_with(
+        iter: impl IntoIterator<Item = (Index, Value)>,
+        len: usize,
+        mut fill: impl FnMut(Index) -> Value,
+    ) -> Self
+    where
+        Index: From<usize> + Into<usize>,
+    {
+        let mut slots: Vec<Option<Value>> = Vec::with_capacity(len);
+        slots.resize_with(len, || None);
+        for (index, value) in iter {
+            slots[index.into()] = Some(value);
+        }
+        slots
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| value.unwrap_or_else(|| fill(index.into())))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
     /// Returns the number of elements in the `TaggedVec`.
     pub fn len(&self) -> usize {
         self.vec.len()
@@ -58,6 +233,20 @@ impl<Index, Value> TaggedVec<Index, Value> {
         self.vec.capacity()
     }
 
+    /// Reserves capacity such that the vector can grow to contain all of the given indices without reallocating.
+    ///
+    /// This allows to allocate once before applying sparse updates that grow the vector step by step.
+    /// Panics if the new capacity exceeds `isize::MAX` bytes, like [`Vec::reserve`].
+    pub fn reserve_for_indices(&mut self, indices: impl IntoIterator<Item = Index>)
+    where
+        Index: Into<usize>,
+    {
+        if let Some(max_index) = indices.into_iter().map(Into::into).max() {
+            self.vec
+                .reserve(max_index.saturating_add(1).saturating_sub(self.vec.len()));
+        }
+    }
+
     /// Returns the untagged slice of the `Vec` underlying this `TaggedVec`.
     pub fn as_untagged_slice(&self) -> &[Value] {
         &self.vec
@@ -68,6 +257,30 @@ impl<Index, Value> TaggedVec<Index, Value> {
         &mut self.vec
     }
 
+    /// Returns the `Vec` underlying this `TaggedVec`.
+    pub fn as_vec(&self) -> &Vec<Value> {
+        &self.vec
+    }
+
+    /// Returns the `Vec` underlying this `TaggedVec` mutably.
+    ///
+    /// Note that structural modifications like inserting or removing values shift the indices of other values, just like the corresponding methods of `TaggedVec`.
+    pub fn as_mut_vec(&mut self) -> &mut Vec<Value> {
+        &mut self.vec
+    }
+
+    /// Reinterprets a reference to a `Vec` as a reference to a `TaggedVec`, without copying.
+    pub fn from_vec_ref(vec: &Vec<Value>) -> &Self {
+        // SAFETY: TaggedVec is #[repr(transparent)] over Vec<Value> and PhantomData<Index> is a 1-ZST.
+        unsafe { &*(vec as *const Vec<Value> as *const Self) }
+    }
+
+    /// Reinterprets a mutable reference to a `Vec` as a mutable reference to a `TaggedVec`, without copying.
+    pub fn from_vec_mut(vec: &mut Vec<Value>) -> &mut Self {
+        // SAFETY: TaggedVec is #[repr(transparent)] over Vec<Value> and PhantomData<Index> is a 1-ZST.
+        unsafe { &mut *(vec as *mut Vec<Value> as *mut Self) }
+    }
+
     /// Inserts the given value at the back of the `TaggedVec`, returning its index.
     pub fn push(&mut self, value: Value) -> Index
     where
@@ -124,6 +337,73 @@ impl<Index, Value> TaggedVec<Index, Value> {
         self.vec.splice(MappedRangeBounds::new(range), replace_with)
     }
 
+    /// Like [`Self::splice`], but eagerly performs the splice and reports the affected indices.
+    ///
+    /// Returns an iterator over the removed values together with their former indices, as well as the range of indices now occupied by the replacement values.
+    pub fn splice_with_indices(
+        &mut self,
+        range: impl RangeBounds<Index>,
+        replace_with: impl IntoIterator<Item = Value>,
+    ) -> (
+        impl DoubleEndedIterator<Item = (Index, Value)> + ExactSizeIterator,
+        TaggedRange<Index>,
+    )
+    where
+        usize: From<Index>,
+        Index: Copy + From<usize>,
+    {
+        let range = MappedRangeBounds::new(range).to_range(self.len());
+        let start = range.start;
+        let old_len = self.len();
+        let removed: Vec<_> = self.vec.splice(range, replace_with).collect();
+        let end = start + self.len() + removed.len() - old_len;
+
+        (
+            removed
+                .into_iter()
+                .enumerate()
+                .map(move |(offset, value)| ((start + offset).into(), value)),
+            TaggedRange::new(start.into(), end.into()),
+        )
+    }
+
+    /// Swaps the contents of this vector with `other` in `O(1)` time, without moving any values.
+    pub fn swap_with(&mut self, other: &mut Self) {
+        std::mem::swap(&mut self.vec, &mut other.vec);
+    }
+
+    /// Replaces the contents of this vector with the values of the given iterator.
+    ///
+    /// The existing allocation is reused, so no reallocation happens if the new values fit into the current capacity.
+    pub fn replace_contents(&mut self, values: impl IntoIterator<Item = Value>) {
+        self.vec.clear();
+        self.vec.extend(values);
+    }
+
+    /// Removes the first `n` values, or all values if there are fewer.
+    ///
+    /// **Warning:** all remaining indices are invalidated, since the value at index `i` moves to index `i - n`.
+    pub fn truncate_front(&mut self, n: usize) {
+        self.vec.drain(..n.min(self.len()));
+    }
+
+    /// Removes all values before the given index and returns them together with their former indices.
+    ///
+    /// **Warning:** all remaining indices are invalidated, since the value at index `i` moves to index `i - end`.
+    /// Panics if `end` is out of bounds.
+    pub fn drain_front(
+        &mut self,
+        end: Index,
+    ) -> impl DoubleEndedIterator<Item = (Index, Value)> + ExactSizeIterator + '_
+    where
+        Index: From<usize> + Into<usize>,
+    {
+        self.vec
+            .drain(..end.into())
+            .enumerate()
+            .map(|(index, value)| (index.into(), value))
+    }
+
     /// Retains only the values specified by the predicate.
     ///
     /// In other words, remove all values `v` for which `f(&v)` returns `false`.
@@ -174,6 +454,112 @@ impl<Index, Value> TaggedVec<Index, Value> {
         self.vec.get(index.into())
     }
 
+    /// Returns a reference to the value at the given index, without doing bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// The `usize` that `index` converts into must be smaller than [`Self::len`].
+    /// This is for example the case for indices returned by [`Self::iter_indices`] as long as the `TaggedVec` was not shortened since.
+    /// See also [`slice::get_unchecked`].
+    pub unsafe fn get_unchecked(&self, index: Index) -> &Value
+    where
+        Index: Into<usize>,
+    {
+        unsafe { self.vec.get_unchecked(index.into()) }
+    }
+
+    /// Returns a mutable reference to the value at the given index, without doing bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// The `usize` that `index` converts into must be smaller than [`Self::len`].
+    /// This is for example the case for indices returned by [`Self::iter_indices`] as long as the `TaggedVec` was not shortened since.
+    /// See also [`slice::get_unchecked_mut`].
+    pub unsafe fn get_unchecked_mut(&mut self, index: Index) -> &mut Value
+    where
+        Index: Into<usize>,
+    {
+        unsafe { self.vec.get_unchecked_mut(index.into()) }
+    }
+
+    /// Returns a reference to the value at the given index modulo the length of the `TaggedVec`, or `None` if it is empty.
+    ///
+    /// This allows ring-like access patterns where indices past the end wrap around to the front.
+    pub fn get_wrapped(&self, index: Index) -> Option<&Value>
+    where
+        Index: Into<usize>,
+    {
+        if self.vec.is_empty() {
+            None
+        } else {
+            Some(&self.vec[index.into() % self.vec.len()])
+        }
+    }
+
+    /// Returns a reference to the value at the given index, or to the last value if the index is out of bounds.
+    ///
+    /// Returns `None` if the `TaggedVec` is empty.
+    pub fn get_clamped(&self, index: Index) -> Option<&Value>
+    where
+        Index: Into<usize>,
+    {
+        self.vec
+            .get(index.into().min(self.vec.len().saturating_sub(1)))
+    }
+
+    /// Returns the index after the given index, or `None` if it is out of bounds.
+    pub fn next_index_of(&self, index: Index) -> Option<Index>
+    where
+        Index: From<usize> + Into<usize>,
+    {
+        let next = index.into().checked_add(1)?;
+        (next < self.vec.len()).then(|| next.into())
+    }
+
+    /// Returns the index before the given index, or `None` if it is out of bounds.
+    pub fn prev_index_of(&self, index: Index) -> Option<Index>
+    where
+        Index: From<usize> + Into<usize>,
+    {
+        let prev = index.into().checked_sub(1)?;
+        (prev < self.vec.len()).then(|| prev.into())
+    }
+
+    /// Replaces the value at the given index with `value`, returning the old value.
+    pub fn replace(&mut self, index: Index, value: Value) -> Value
+    where
+        Index: Into<usize>,
+    {
+        std::mem::replace(&mut self.vec[index.into()], value)
+    }
+
+    /// Takes the value at the given index, leaving [`Default::default`] in its place.
+    pub fn take_at(&mut self, index: Index) -> Value
+    where
+        Index: Into<usize>,
+        Value: Default,
+    {
+        std::mem::take(&mut self.vec[index.into()])
+    }
+
+    /// Calls `f` with a mutable reference to the value at the given index, returning the result of `f`.
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn update<R>(&mut self, index: Index, f: impl FnOnce(&mut Value) -> R) -> R
+    where
+        Index: Into<usize>,
+    {
+        f(&mut self.vec[index.into()])
+    }
+
+    /// Calls `f` with a mutable reference to the value at the given index, returning the result of `f`, or `None` if the index is out of bounds.
+    pub fn try_update<R>(&mut self, index: Index, f: impl FnOnce(&mut Value) -> R) -> Option<R>
+    where
+        Index: Into<usize>,
+    {
+        self.vec.get_mut(index.into()).map(f)
+    }
+
     /// Returns an iterator over references to the entries of the `TaggedVec`.
     ///
     /// The `range` specifies which subset of the entries to iterate over.
@@ -238,6 +624,14 @@ impl<Index, Value> TaggedVec<Index, Value> {
             .map(|(index, value)| (index.into(), value))
     }
 
+    /// Returns an iterator over references to the entries of the `TaggedVec`, starting from the last index.
+    pub fn iter_rev(&self) -> std::iter::Rev<EntryIterator<'_, Index, Value>>
+    where
+        Index: From<usize>,
+    {
+        EntryIterator::new(&self.vec).rev()
+    }
+
     /// Returns an iterator over references to the values of the `TaggedVec`.
     pub fn iter_values(&self) -> std::slice::Iter<'_, Value> {
         self.vec.iter()
@@ -248,6 +642,49 @@ impl<Index, Value> TaggedVec<Index, Value> {
         self.vec.iter_mut()
     }
 
+    /// Returns an iterator over references to the values in the given range of the `TaggedVec`.
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn iter_values_range(&self, range: impl RangeBounds<Index>) -> std::slice::Iter<'_, Value>
+    where
+        Index: Copy,
+        usize: From<Index>,
+    {
+        self.vec[MappedRangeBounds::new(range).into_bounds()].iter()
+    }
+
+    /// Returns an iterator over mutable references to the values in the given range of the `TaggedVec`.
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn iter_values_range_mut(
+        &mut self,
+        range: impl RangeBounds<Index>,
+    ) -> std::slice::IterMut<'_, Value>
+    where
+        Index: Copy,
+        usize: From<Index>,
+    {
+        self.vec[MappedRangeBounds::new(range).into_bounds()].iter_mut()
+    }
+
+    /// Returns an iterator over disjoint consecutive ranges of length `chunk_len` that cover all indices of the `TaggedVec`.
+    ///
+    /// The last range is shorter if the length is not divisible by `chunk_len`.
+    /// Panics if `chunk_len` is zero.
+    pub fn index_chunks(
+        &self,
+        chunk_len: usize,
+    ) -> impl DoubleEndedIterator<Item = TaggedRange<Index>>
+    where
+        Index: From<usize>,
+    {
+        assert!(chunk_len > 0, "chunk length must be non-zero");
+        let len = self.vec.len();
+        (0..len)
+            .step_by(chunk_len)
+            .map(move |start| TaggedRange::new(start.into(), (start + chunk_len).min(len).into()))
+    }
+
     /// Returns an iterator over the indices of the `TaggedVec`.
     ///
     /// The `range` specifies which subset of the entries to iterate over.
@@ -272,6 +709,24 @@ impl<Index, Value> TaggedVec<Index, Value> {
         IndexIterator::new(start_index_inclusive, end_index_exclusive)
     }
 
+    /// Returns an iterator over all indices of the `TaggedVec`, starting from the last index.
+    pub fn iter_indices_rev(&self) -> std::iter::Rev<IndexIterator<Index>>
+    where
+        Index: From<usize>,
+    {
+        IndexIterator::new(0, self.vec.len()).rev()
+    }
+
+    /// Returns an iterator over every `step`-th index of the `TaggedVec`, starting from the first index.
+    ///
+    /// Panics if `step` is zero.
+    pub fn iter_indices_step(&self, step: usize) -> std::iter::StepBy<IndexIterator<Index>>
+    where
+        Index: From<usize>,
+    {
+        IndexIterator::new(0, self.vec.len()).step_by(step)
+    }
+
     /// Consumes the `TaggedVec`, returning an iterator over the entries.
     ///
     /// The `range` specifies which subset of the entries to iterate over.
@@ -308,4 +763,25 @@ impl<Index, Value> TaggedVec<Index, Value> {
     pub fn into_values_iter(self) -> std::vec::IntoIter<Value> {
         self.vec.into_iter()
     }
+
+    /// Converts the `TaggedVec` into an array of its values.
+    ///
+    /// Returns `self` as error if its length is not `N`.
+    pub fn try_into_array<const N: usize>(self) -> Result<[Value; N], Self> {
+        self.vec.try_into().map_err(Self::from)
+    }
+
+    /// Splits off the last `N` values into an array, returning the remaining vector and the array.
+    ///
+    /// Returns `self` as error if its length is less than `N`.
+    pub fn try_split_off_array<const N: usize>(mut self) -> Result<(Self, [Value; N]), Self> {
+        if self.len() < N {
+            return Err(self);
+        }
+        let tail = self.vec.split_off(self.len() - N);
+        let Ok(array) = tail.try_into() else {
+            unreachable!("split off exactly N values")
+        };
+        Ok((self, array))
+    }
 }
@@ -0,0 +1,62 @@
+//! Aliases for iterating a `TaggedVec` with the terminology of maps like [`HashMap`](std::collections::HashMap).
+
+use crate::{EntryIterator, EntryIteratorMut, IndexIterator, TaggedVec};
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Returns an iterator over all indices in ascending order.
+    pub fn keys(&self) -> IndexIterator<Index> {
+        IndexIterator::new(0, self.vec.len())
+    }
+
+    /// Returns an iterator over references to all values, like [`Self::iter_values`].
+    pub fn values(&self) -> std::slice::Iter<'_, Value> {
+        self.vec.iter()
+    }
+
+    /// Returns an iterator over mutable references to all values, like [`Self::iter_values_mut`].
+    pub fn values_mut(&mut self) -> std::slice::IterMut<'_, Value> {
+        self.vec.iter_mut()
+    }
+
+    /// Returns an iterator over references to all entries, like [`Self::iter`] with an unbounded range.
+    pub fn entries(&self) -> EntryIterator<'_, Index, Value> {
+        EntryIterator::new(&self.vec)
+    }
+
+    /// Returns an iterator over mutable references to all entries, like [`Self::iter_mut`] with an unbounded range.
+    pub fn entries_mut(&mut self) -> EntryIteratorMut<'_, Index, Value> {
+        EntryIteratorMut::new(&mut self.vec)
+    }
+
+    /// Consumes the `TaggedVec`, returning an iterator over all entries.
+    pub fn into_entries(self) -> impl DoubleEndedIterator<Item = (Index, Value)> + ExactSizeIterator
+    where
+        Index: From<usize>,
+    {
+        self.vec
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| (index.into(), value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TaggedVec;
+
+    #[test]
+    fn map_api() {
+        let mut vec = TaggedVec::<usize, _>::from(vec!['a', 'b']);
+        vec.values_mut()
+            .for_each(|value| value.make_ascii_uppercase());
+        assert_eq!(vec.keys().collect::<Vec<_>>(), [0, 1]);
+        assert_eq!(vec.values().collect::<Vec<_>>(), [&'A', &'B']);
+        for (index, value) in vec.entries_mut() {
+            if index == 1 {
+                *value = 'C';
+            }
+        }
+        assert_eq!(vec.entries().collect::<Vec<_>>(), [(0, &'A'), (1, &'C')]);
+        assert_eq!(vec.into_entries().collect::<Vec<_>>(), [(0, 'A'), (1, 'C')]);
+    }
+}
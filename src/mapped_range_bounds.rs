@@ -1,4 +1,4 @@
-use std::ops::{Bound, RangeBounds};
+use std::ops::{Bound, Range, RangeBounds};
 
 pub struct MappedRangeBounds {
     start_bound: Bound<usize>,
@@ -28,6 +28,36 @@ impl MappedRangeBounds {
             end_bound,
         }
     }
+
+    /// Resolves the bounds into a range within `0..len`.
+    ///
+    /// Panics if the range is out of bounds or decreasing, like slice indexing does.
+    pub fn to_range(&self, len: usize) -> Range<usize> {
+        let start = match self.start_bound {
+            Bound::Included(start) => start,
+            Bound::Excluded(start) => start.checked_add(1).expect("start index overflow"),
+            Bound::Unbounded => 0,
+        };
+        let end = match self.end_bound {
+            Bound::Included(end) => end.checked_add(1).expect("end index overflow"),
+            Bound::Excluded(end) => end,
+            Bound::Unbounded => len,
+        };
+        assert!(
+            start <= end,
+            "range start {start} is greater than its end {end}"
+        );
+        assert!(
+            end <= len,
+            "range end {end} is out of bounds of length {len}"
+        );
+        start..end
+    }
+
+    /// Returns the bounds as a pair, which can be used to index slices.
+    pub fn into_bounds(self) -> (Bound<usize>, Bound<usize>) {
+        (self.start_bound, self.end_bound)
+    }
 }
 
 impl RangeBounds<usize> for MappedRangeBounds {
@@ -0,0 +1,97 @@
+//! Iteration over the entries of a `TaggedVec` selected by a [`TaggedBitSet`].
+
+use std::thread;
+
+use crate::{TaggedBitSet, TaggedRange, TaggedVec};
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Returns an iterator over the entries whose indices are in `mask`, in order of their indices.
+    ///
+    /// The bit set is scanned one word of 64 bits at a time, so the cost is `O(len / 64 + selected)` for the length `len` of the vector and the number of selected entries `selected`.
+    /// Indices in `mask` that are out of bounds are ignored.
+    pub fn iter_masked<'a>(
+        &'a self,
+        mask: &'a TaggedBitSet<Index>,
+    ) -> impl Iterator<Item = (Index, &'a Value)>
+    where
+        Index: From<usize>,
+    {
+        mask.iter_from::<usize>(0)
+            .take_while(|index| *index < self.vec.len())
+            .map(|index| (index.into(), &self.vec[index]))
+    }
+
+    /// Returns an iterator over mutable references to the entries whose indices are in `mask`, in order of their indices.
+    ///
+    /// See [`Self::iter_masked`].
+    pub fn iter_masked_mut<'a>(
+        &'a mut self,
+        mask: &'a TaggedBitSet<Index>,
+    ) -> impl Iterator<Item = (Index, &'a mut Value)>
+    where
+        Index: From<usize>,
+    {
+        let mut indices = mask.iter_from::<usize>(0);
+        let mut rest = self.vec.as_mut_slice();
+        let mut offset = 0;
+        std::iter::from_fn(move || {
+            let index = indices.next()?;
+            let (value, remainder) = std::mem::take(&mut rest)
+                .get_mut(index - offset..)?
+                .split_first_mut()?;
+            rest = remainder;
+            offset = index + 1;
+            Some((index.into(), value))
+        })
+    }
+
+    /// Calls `f` on each entry whose index is in `mask`, distributing the work over `num_threads` scoped threads.
+    ///
+    /// The vector is split like in [`Self::par_for_each_with_index`], and each thread visits only the set bits of `mask` in its part.
+    /// Panics if `num_threads` is zero, or if `f` panics.
+    pub fn par_for_each_masked(
+        &mut self,
+        num_threads: usize,
+        mask: &TaggedBitSet<Index>,
+        f: impl Fn(Index, &mut Value) + Sync,
+    ) where
+        Index: From<usize>,
+        Value: Send,
+    {
+        let f = &f;
+        let mut rest = self.vec.as_mut_slice();
+        thread::scope(|scope| {
+            for range in TaggedRange::<usize>::new(0, rest.len()).split_into(num_threads) {
+                let (part, remainder) = std::mem::take(&mut rest).split_at_mut(range.len());
+                rest = remainder;
+                let indices = mask.iter_from::<usize>(range.start);
+                scope.spawn(move || {
+                    for index in indices.take_while(|index| *index < range.end) {
+                        f(index.into(), &mut part[index - range.start]);
+                    }
+                });
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TaggedBitSet, TaggedVec};
+
+    #[test]
+    fn iter_masked() {
+        let mut vec = TaggedVec::<usize, _>::from(vec![0; 100]);
+        let mask: TaggedBitSet<usize> = [1, 50, 99, 150].into_iter().collect();
+
+        for (index, value) in vec.iter_masked_mut(&mask) {
+            *value = index;
+        }
+        vec.par_for_each_masked(3, &mask, |_, value| *value += 1);
+        assert_eq!(
+            vec.iter_masked(&mask).collect::<Vec<_>>(),
+            [(1, &2), (50, &51), (99, &100)]
+        );
+        assert_eq!(vec.iter_values().filter(|value| **value != 0).count(), 3);
+    }
+}
@@ -0,0 +1,240 @@
+//! Merging and interleaving of `TaggedVec`s.
+
+use std::cmp::Ordering;
+
+use crate::TaggedVec;
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Merges two vectors sorted by `cmp` into a single sorted vector in `O(n + m)` time.
+    ///
+    /// The merge is stable, i.e. values of `self` are placed before equal values of `other`.
+    pub fn merge_sorted(
+        self,
+        other: Self,
+        mut cmp: impl FnMut(&Value, &Value) -> Ordering,
+    ) -> Self {
+        merge_two(self.vec, other.vec, &mut cmp).into()
+    }
+
+    /// Like [`Self::merge_sorted`], but additionally returns the origin of each output value.
+    ///
+    /// The origin is a pair of the input number (`0` for `self` and `1` for `other`) and the index within that input.
+    pub fn merge_sorted_with_origin(
+        self,
+        other: Self,
+        cmp: impl FnMut(&Value, &Value) -> Ordering,
+    ) -> (Self, TaggedVec<Index, (usize, Index)>)
+    where
+        Index: From<usize>,
+    {
+        Self::merge_sorted_many_with_origin([self, other], cmp)
+    }
+
+    /// Merges any number of vectors sorted by `cmp` into a single sorted vector in `O(n log k)` time, where `k` is the number of inputs.
+    ///
+    /// The merge is stable, i.e. equal values are ordered by the position of their input in `inputs`.
+    pub fn merge_sorted_many(
+        inputs: impl IntoIterator<Item = Self>,
+        mut cmp: impl FnMut(&Value, &Value) -> Ordering,
+    ) -> Self {
+        merge_many(
+            inputs.into_iter().map(|input| input.vec).collect(),
+            &mut cmp,
+        )
+        .into()
+    }
+
+    /// Like [`Self::merge_sorted_many`], but additionally returns the origin of each output value.
+    ///
+    /// The origin is a pair of the position of the input in `inputs` and the index within that input.
+    pub fn merge_sorted_many_with_origin(
+        inputs: impl IntoIterator<Item = Self>,
+        mut cmp: impl FnMut(&Value, &Value) -> Ordering,
+    ) -> (Self, TaggedVec<Index, (usize, Index)>)
+    where
+        Index: From<usize>,
+    {
+        let runs = inputs
+            .into_iter()
+            .enumerate()
+            .map(|(input, run)| {
+                run.vec
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, value)| ((input, index), value))
+                    .collect()
+            })
+            .collect();
+        let (origins, values): (Vec<_>, Vec<_>) = merge_many(runs, &mut |(_, a), (_, b)| cmp(a, b))
+            .into_iter()
+            .unzip();
+
+        (
+            values.into(),
+            origins
+                .into_iter()
+                .map(|(input, index)| (input, index.into()))
+                .collect(),
+        )
+    }
+
+    /// Interleaves the values of `self` and `other`, starting with `self`.
+    ///
+    /// When one vector runs out of values, the remaining values of the other are appended.
+    /// Additionally returns the origin of each output value, like [`Self::merge_sorted_with_origin`].
+    pub fn interleave(self, other: Self) -> (Self, TaggedVec<Index, (usize, Index)>)
+    where
+        Index: From<usize>,
+    {
+        let len = self.len() + other.len();
+        let mut values = Vec::with_capacity(len);
+        let mut origins = Vec::with_capacity(len);
+        let mut inputs = [self.vec.into_iter(), other.vec.into_iter()];
+
+        for index in 0.. {
+            let mut exhausted = true;
+            for (input, values_iter) in inputs.iter_mut().enumerate() {
+                if let Some(value) = values_iter.next() {
+                    values.push(value);
+                    origins.push((input, index.into()));
+                    exhausted = false;
+                }
+            }
+            if exhausted {
+                break;
+            }
+        }
+
+        (values.into(), origins.into())
+    }
+
+    /// Combines two vectors over the same indices by choosing the value of one of them at each index.
+    ///
+    /// At each index present in both vectors, `f` decides whether the value of `self` (`true`) or of `other` (`false`) is kept.
+    /// At indices present in only one of the vectors, the value of that vector is kept.
+    /// Additionally returns the input number (`0` for `self` and `1` for `other`) that each output value came from.
+    pub fn merge_by(
+        self,
+        other: Self,
+        mut f: impl FnMut(Index, &Value, &Value) -> bool,
+    ) -> (Self, TaggedVec<Index, usize>)
+    where
+        Index: From<usize>,
+    {
+        let len = self.len().max(other.len());
+        let mut values = Vec::with_capacity(len);
+        let mut origins = Vec::with_capacity(len);
+        let mut a = self.vec.into_iter();
+        let mut b = other.vec.into_iter();
+
+        for index in 0..len {
+            let (value, origin) = match (a.next(), b.next()) {
+                (Some(a_value), Some(b_value)) => {
+                    if f(index.into(), &a_value, &b_value) {
+                        (a_value, 0)
+                    } else {
+                        (b_value, 1)
+                    }
+                }
+                (Some(a_value), None) => (a_value, 0),
+                (None, Some(b_value)) => (b_value, 1),
+                (None, None) => unreachable!(),
+            };
+            values.push(value);
+            origins.push(origin);
+        }
+
+        (values.into(), origins.into())
+    }
+}
+
+/// Merges the runs pairwise in rounds, which keeps the merge stable.
+fn merge_many<T>(mut runs: Vec<Vec<T>>, cmp: &mut impl FnMut(&T, &T) -> Ordering) -> Vec<T> {
+    while runs.len() > 1 {
+        let mut merged = Vec::with_capacity(runs.len().div_ceil(2));
+        let mut runs_iter = runs.into_iter();
+        while let Some(a) = runs_iter.next() {
+            merged.push(match runs_iter.next() {
+                Some(b) => merge_two(a, b, cmp),
+                None => a,
+            });
+        }
+        runs = merged;
+    }
+
+    runs.pop().unwrap_or_default()
+}
+
+fn merge_two<T>(a: Vec<T>, b: Vec<T>, cmp: &mut impl FnMut(&T, &T) -> Ordering) -> Vec<T> {
+    let mut result = Vec::with_capacity(a.len() + b.len());
+    let mut a = a.into_iter().peekable();
+    let mut b = b.into_iter().peekable();
+
+    loop {
+        let take_a = match (a.peek(), b.peek()) {
+            (Some(a_value), Some(b_value)) => cmp(a_value, b_value) != Ordering::Greater,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => break,
+        };
+
+        result.push(if take_a { a.next() } else { b.next() }.unwrap());
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TaggedVec;
+
+    #[test]
+    fn merge_sorted() {
+        let a = TaggedVec::<usize, _>::from(vec![(1, 'a'), (3, 'a'), (5, 'a')]);
+        let b = TaggedVec::from(vec![(1, 'b'), (2, 'b'), (6, 'b')]);
+        let c = TaggedVec::from(vec![(0, 'c'), (3, 'c')]);
+
+        let merged = a.clone().merge_sorted(b.clone(), |x, y| x.0.cmp(&y.0));
+        assert_eq!(
+            merged,
+            [(1, 'a'), (1, 'b'), (2, 'b'), (3, 'a'), (5, 'a'), (6, 'b')]
+        );
+
+        let (merged, origin) =
+            TaggedVec::merge_sorted_many_with_origin([a, b, c], |x, y| x.0.cmp(&y.0));
+        assert_eq!(
+            merged
+                .iter_values()
+                .map(|value| value.0)
+                .collect::<Vec<_>>(),
+            [0, 1, 1, 2, 3, 3, 5, 6]
+        );
+        assert_eq!(
+            origin,
+            [
+                (2, 0),
+                (0, 0),
+                (1, 0),
+                (1, 1),
+                (0, 1),
+                (2, 1),
+                (0, 2),
+                (1, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn interleave_and_merge_by() {
+        let old = TaggedVec::<usize, _>::from(vec![1, 5, 3]);
+        let new = TaggedVec::from(vec![2, 4]);
+
+        let (interleaved, origin) = old.clone().interleave(new.clone());
+        assert_eq!(interleaved, [1, 2, 5, 4, 3]);
+        assert_eq!(origin, [(0, 0), (1, 0), (0, 1), (1, 1), (0, 2)]);
+
+        let (merged, origin) = old.merge_by(new, |_, a, b| a > b);
+        assert_eq!(merged, [2, 5, 3]);
+        assert_eq!(origin, [1, 0, 0]);
+    }
+}
@@ -0,0 +1,136 @@
+//! Deferred batched mutation of `TaggedVec`s.
+
+use crate::TaggedVec;
+
+/// A queue of removals, insertions and updates that are applied to a [`TaggedVec`] at once by [`Self::commit`].
+///
+/// All indices refer to the vector as it was before committing, so mutations can be queued while iterating over the vector.
+/// Committing takes a single pass over the vector, instead of shifting values once per mutation.
+pub struct MutationBatch<Index, Value> {
+    removals: Vec<Index>,
+    insertions: Vec<(Index, Value)>,
+    updates: Vec<(Index, Value)>,
+}
+
+impl<Index, Value> MutationBatch<Index, Value> {
+    /// Creates a new empty `MutationBatch`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if no mutations are queued.
+    pub fn is_empty(&self) -> bool {
+        self.removals.is_empty() && self.insertions.is_empty() && self.updates.is_empty()
+    }
+
+    /// Queues the removal of the value at `index`.
+    ///
+    /// A removal takes precedence over updates of the same index.
+    pub fn remove(&mut self, index: Index) {
+        self.removals.push(index);
+    }
+
+    /// Queues the insertion of `value` before the value at `index`, or at the back if `index` is the length of the vector.
+    ///
+    /// Multiple values inserted at the same index keep the order in which they were queued.
+    pub fn insert(&mut self, index: Index, value: Value) {
+        self.insertions.push((index, value));
+    }
+
+    /// Queues replacing the value at `index` with `value`.
+    ///
+    /// If the same index is updated multiple times, the last update wins.
+    pub fn update(&mut self, index: Index, value: Value) {
+        self.updates.push((index, value));
+    }
+
+    /// Applies all queued mutations to the given vector.
+    ///
+    /// Returns a remap from each old index to the new index of its value, or `None` if the value was removed.
+    /// Panics if an index is out of bounds.
+    pub fn commit(self, vec: &mut TaggedVec<Index, Value>) -> TaggedVec<Index, Option<Index>>
+    where
+        Index: From<usize> + Into<usize>,
+    {
+        let len = vec.len();
+        let mut removed = vec![false; len];
+        for index in self.removals {
+            removed[index.into()] = true;
+        }
+        let mut updates: Vec<Option<Value>> = Vec::with_capacity(len);
+        updates.resize_with(len, || None);
+        for (index, value) in self.updates {
+            updates[index.into()] = Some(value);
+        }
+        let mut insertions: Vec<_> = self
+            .insertions
+            .into_iter()
+            .map(|(index, value)| {
+                let index = index.into();
+                assert!(index <= len, "insertion index {index} out of bounds");
+                (index, value)
+            })
+            .collect();
+        insertions.sort_by_key(|(index, _)| *index);
+        let mut insertions = insertions.into_iter().peekable();
+
+        let mut result = Vec::with_capacity(len + insertions.len());
+        let mut remap = Vec::with_capacity(len);
+        for (old_index, ((value, removed), update)) in std::mem::take(&mut vec.vec)
+            .into_iter()
+            .zip(removed)
+            .zip(updates)
+            .enumerate()
+        {
+            while let Some((_, value)) = insertions.next_if(|(index, _)| *index == old_index) {
+                result.push(value);
+            }
+
+            if removed {
+                remap.push(None);
+            } else {
+                remap.push(Some(result.len().into()));
+                result.push(update.unwrap_or(value));
+            }
+        }
+        result.extend(insertions.map(|(_, value)| value));
+
+        vec.vec = result;
+        remap.into()
+    }
+}
+
+impl<Index, Value> Default for MutationBatch<Index, Value> {
+    fn default() -> Self {
+        Self {
+            removals: Default::default(),
+            insertions: Default::default(),
+            updates: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MutationBatch;
+    use crate::TaggedVec;
+
+    #[test]
+    fn commit() {
+        let mut vec = TaggedVec::<usize, _>::from(vec!['a', 'b', 'c', 'd']);
+        let mut batch = MutationBatch::new();
+        for (index, value) in &vec {
+            match value {
+                'a' => batch.remove(index),
+                'b' => batch.update(index, 'B'),
+                'c' => batch.insert(index, 'x'),
+                _ => batch.insert(index + 1, 'y'),
+            }
+        }
+        batch.insert(2, 'z');
+
+        let remap = batch.commit(&mut vec);
+        assert_eq!(vec, ['B', 'x', 'z', 'c', 'd', 'y']);
+        assert_eq!(remap, [None, Some(0), Some(3), Some(4)]);
+    }
+}
@@ -0,0 +1,152 @@
+//! A jagged two-level `TaggedVec` stored in compressed sparse row format.
+
+use crate::{TaggedRange, TaggedVec};
+
+/// A vector of variable-length rows indexed by `OuterIndex`, whose values are indexed by `InnerIndex`.
+///
+/// All values are stored in a single flat `TaggedVec<InnerIndex, Value>`, and each row is a range of this vector.
+/// This avoids one allocation per row, which makes it suitable for adjacency lists and similar data.
+pub struct TaggedNestedVec<OuterIndex, InnerIndex, Value> {
+    rows: TaggedVec<OuterIndex, TaggedRange<InnerIndex>>,
+    values: TaggedVec<InnerIndex, Value>,
+}
+
+impl<OuterIndex, InnerIndex, Value> TaggedNestedVec<OuterIndex, InnerIndex, Value> {
+    /// Creates a new `TaggedNestedVec` without rows.
+    pub fn new() -> Self {
+        Self {
+            rows: TaggedVec::new(),
+            values: TaggedVec::new(),
+        }
+    }
+
+    /// Returns the number of rows.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns `true` if there are no rows.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Returns the flat vector of the values of all rows.
+    pub fn values(&self) -> &TaggedVec<InnerIndex, Value> {
+        &self.values
+    }
+
+    /// Returns the vector of the ranges of all rows in [`Self::values`].
+    pub fn row_ranges(&self) -> &TaggedVec<OuterIndex, TaggedRange<InnerIndex>> {
+        &self.rows
+    }
+
+    /// Appends a row containing the given values, returning its index.
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = Value>) -> OuterIndex
+    where
+        OuterIndex: From<usize>,
+        InnerIndex: From<usize>,
+    {
+        let start = self.values.len();
+        self.values.extend(row);
+        self.rows
+            .push(TaggedRange::new(start.into(), self.values.len().into()))
+    }
+
+    /// Returns the range of the given row in [`Self::values`].
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn row_range(&self, index: OuterIndex) -> TaggedRange<InnerIndex>
+    where
+        OuterIndex: Into<usize>,
+        InnerIndex: Copy,
+    {
+        self.rows[index]
+    }
+
+    /// Returns the values of the given row.
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn row(&self, index: OuterIndex) -> &[Value]
+    where
+        OuterIndex: Into<usize>,
+        InnerIndex: Into<usize> + Copy,
+    {
+        let range = self.rows[index];
+        &self.values.vec[range.start.into()..range.end.into()]
+    }
+
+    /// Returns the values of the given row mutably.
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn row_mut(&mut self, index: OuterIndex) -> &mut [Value]
+    where
+        OuterIndex: Into<usize>,
+        InnerIndex: Into<usize> + Copy,
+    {
+        let range = self.rows[index];
+        &mut self.values.vec[range.start.into()..range.end.into()]
+    }
+
+    /// Returns an iterator over the rows in order of their indices.
+    pub fn iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (OuterIndex, &[Value])> + ExactSizeIterator
+    where
+        OuterIndex: From<usize>,
+        InnerIndex: Into<usize> + Copy,
+    {
+        self.rows.vec.iter().enumerate().map(|(index, range)| {
+            (
+                index.into(),
+                &self.values.vec[range.start.into()..range.end.into()],
+            )
+        })
+    }
+}
+
+impl<OuterIndex, InnerIndex, Value> Default for TaggedNestedVec<OuterIndex, InnerIndex, Value> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<OuterIndex, InnerIndex, Value, Row: IntoIterator<Item = Value>> FromIterator<Row>
+    for TaggedNestedVec<OuterIndex, InnerIndex, Value>
+where
+    OuterIndex: From<usize>,
+    InnerIndex: From<usize>,
+{
+    fn from_iter<T: IntoIterator<Item = Row>>(iter: T) -> Self {
+        let mut result = Self::new();
+        for row in iter {
+            result.push_row(row);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TaggedNestedVec;
+    use crate::TaggedRange;
+
+    #[test]
+    fn push_row() {
+        let mut adjacency: TaggedNestedVec<usize, usize, usize> =
+            [vec![1, 2], vec![], vec![0]].into_iter().collect();
+        assert_eq!(adjacency.push_row([0, 1, 2]), 3);
+        adjacency.row_mut(0)[1] = 3;
+
+        assert_eq!(adjacency.row(0), [1, 3]);
+        assert!(adjacency.row(1).is_empty());
+        assert_eq!(adjacency.row_range(3), TaggedRange::new(3, 6));
+        assert_eq!(adjacency.values().len(), 6);
+        assert_eq!(
+            adjacency
+                .iter()
+                .map(|(_, row)| row.len())
+                .collect::<Vec<_>>(),
+            [2, 0, 1, 3]
+        );
+    }
+}
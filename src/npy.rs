@@ -0,0 +1,233 @@
+//! Import and export of `TaggedVec`s in the NumPy `.npy` format.
+//!
+//! A `TaggedVec` is stored as a one-dimensional array.
+//! Values are written in little-endian byte order, and both byte orders are accepted when reading.
+
+use std::io::{Error, ErrorKind, Read, Write};
+
+use crate::TaggedVec;
+
+const MAGIC: &[u8; 6] = b"\x93NUMPY";
+/// The total length of magic, version, header length and header is padded to a multiple of this.
+const HEADER_ALIGNMENT: usize = 64;
+
+/// A primitive type that can be stored in a `.npy` file.
+pub trait NpyElement: Copy {
+    /// The NumPy type kind, e.g. `'u'` for unsigned integers.
+    const KIND: char;
+    /// The size of the type in bytes.
+    const SIZE: usize;
+
+    /// Appends the little-endian bytes of this value to `bytes`.
+    fn write_le_bytes(&self, bytes: &mut Vec<u8>);
+
+    /// Reads a value from exactly [`Self::SIZE`] bytes in the given byte order.
+    ///
+    /// Returns `None` if the bytes are not a valid value.
+    fn read_bytes(bytes: &[u8], little_endian: bool) -> Option<Self>;
+}
+
+macro_rules! impl_npy_element {
+    ($kind:literal, $($type:ty),*) => {
+        $(
+            impl NpyElement for $type {
+                const KIND: char = $kind;
+                const SIZE: usize = std::mem::size_of::<$type>();
+
+                fn write_le_bytes(&self, bytes: &mut Vec<u8>) {
+                    bytes.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn read_bytes(bytes: &[u8], little_endian: bool) -> Option<Self> {
+                    let bytes = bytes.try_into().ok()?;
+                    Some(if little_endian {
+                        Self::from_le_bytes(bytes)
+                    } else {
+                        Self::from_be_bytes(bytes)
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_npy_element!('u', u8, u16, u32, u64);
+impl_npy_element!('i', i8, i16, i32, i64);
+impl_npy_element!('f', f32, f64);
+
+impl NpyElement for bool {
+    const KIND: char = 'b';
+    const SIZE: usize = 1;
+
+    fn write_le_bytes(&self, bytes: &mut Vec<u8>) {
+        bytes.push(u8::from(*self));
+    }
+
+    fn read_bytes(bytes: &[u8], _little_endian: bool) -> Option<Self> {
+        match bytes {
+            [0] => Some(false),
+            [1] => Some(true),
+            _ => None,
+        }
+    }
+}
+
+impl<Index, Value: NpyElement> TaggedVec<Index, Value> {
+    /// Writes this `TaggedVec` as a one-dimensional array in `.npy` format (version 1.0).
+    pub fn write_npy(&self, mut writer: impl Write) -> std::io::Result<()> {
+        let mut header = format!(
+            "{{'descr': '{}', 'fortran_order': False, 'shape': ({},), }}",
+            npy_descr::<Value>(),
+            self.len()
+        );
+        let unpadded_len = MAGIC.len() + 2 + 2 + header.len() + 1;
+        let padded_len = unpadded_len.next_multiple_of(HEADER_ALIGNMENT);
+        header.extend(std::iter::repeat_n(' ', padded_len - unpadded_len));
+        header.push('\n');
+        let header_len = u16::try_from(header.len())
+            .map_err(|_| Error::new(ErrorKind::InvalidInput, "npy header too long"))?;
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[1, 0])?;
+        writer.write_all(&header_len.to_le_bytes())?;
+        writer.write_all(header.as_bytes())?;
+
+        let mut buffer = Vec::new();
+        for chunk in self.vec.chunks(64 * 1024) {
+            buffer.clear();
+            for value in chunk {
+                value.write_le_bytes(&mut buffer);
+            }
+            writer.write_all(&buffer)?;
+        }
+        Ok(())
+    }
+
+    /// Reads a `TaggedVec` from a one-dimensional array in `.npy` format.
+    ///
+    /// Fails with [`ErrorKind::InvalidData`] if the file is not a valid `.npy` file, is not one-dimensional, or stores a different value type.
+    pub fn read_npy(mut reader: impl Read) -> std::io::Result<Self> {
+        let mut magic = [0; MAGIC.len()];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid_data("not an npy file".to_owned()));
+        }
+
+        let mut version = [0; 2];
+        reader.read_exact(&mut version)?;
+        let header_len = match version[0] {
+            1 => {
+                let mut buffer = [0; 2];
+                reader.read_exact(&mut buffer)?;
+                usize::from(u16::from_le_bytes(buffer))
+            }
+            2 | 3 => {
+                let mut buffer = [0; 4];
+                reader.read_exact(&mut buffer)?;
+                u32::from_le_bytes(buffer) as usize
+            }
+            major => return Err(invalid_data(format!("unsupported npy version {major}"))),
+        };
+        let mut header = vec![0; header_len];
+        reader.read_exact(&mut header)?;
+        let header = String::from_utf8(header).map_err(|error| invalid_data(error.to_string()))?;
+
+        let descr = header_value(&header, "descr")?
+            .trim_matches(|c| c == '\'' || c == '"')
+            .to_owned();
+        let little_endian = match descr.chars().next() {
+            Some('<') => true,
+            Some('>') => false,
+            Some('|' | '=') => cfg!(target_endian = "little"),
+            _ => return Err(invalid_data(format!("invalid npy descr {descr:?}"))),
+        };
+        if descr[1..] != npy_descr::<Value>()[1..] {
+            return Err(invalid_data(format!(
+                "npy type mismatch: expected {:?}, but found {descr:?}",
+                npy_descr::<Value>()
+            )));
+        }
+
+        let shape = header_value(&header, "shape")?;
+        let len = shape
+            .trim_start_matches('(')
+            .trim_end_matches(')')
+            .trim_end_matches(',')
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| invalid_data(format!("npy shape {shape} is not one-dimensional")))?;
+
+        let bytes_len = len
+            .checked_mul(Value::SIZE)
+            .ok_or_else(|| invalid_data(format!("npy shape {shape} is too large")))?;
+        let mut bytes = Vec::new();
+        reader.take(bytes_len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != bytes_len {
+            return Err(Error::from(ErrorKind::UnexpectedEof));
+        }
+        bytes
+            .chunks_exact(Value::SIZE)
+            .map(|bytes| {
+                Value::read_bytes(bytes, little_endian)
+                    .ok_or_else(|| invalid_data("invalid npy value".to_owned()))
+            })
+            .collect()
+    }
+}
+
+fn npy_descr<Value: NpyElement>() -> String {
+    let byte_order = if Value::SIZE == 1 { '|' } else { '<' };
+    format!("{byte_order}{}{}", Value::KIND, Value::SIZE)
+}
+
+/// Returns the value of the given key in the Python dictionary literal `header`.
+fn header_value<'header>(header: &'header str, key: &str) -> std::io::Result<&'header str> {
+    let missing_key = || invalid_data(format!("npy header misses key {key:?}"));
+    let start = header.find(&format!("'{key}'")).ok_or_else(missing_key)? + key.len() + 2;
+    let value = header[start..]
+        .trim_start()
+        .strip_prefix(':')
+        .ok_or_else(missing_key)?
+        .trim_start();
+    let end = if value.starts_with('(') {
+        value.find(')').map(|end| end + 1)
+    } else {
+        value.find([',', '}'])
+    }
+    .ok_or_else(missing_key)?;
+    Ok(&value[..end])
+}
+
+fn invalid_data(message: String) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TaggedVec;
+
+    #[test]
+    fn npy_roundtrip() {
+        let vec = TaggedVec::<usize, i32>::from(vec![-1, 2, 300]);
+        let mut bytes = Vec::new();
+        vec.write_npy(&mut bytes).unwrap();
+        assert_eq!(&bytes[..10], b"\x93NUMPY\x01\x00\x76\x00");
+        assert_eq!(bytes.len(), 128 + 12);
+        assert_eq!(TaggedVec::read_npy(bytes.as_slice()).unwrap(), vec);
+        assert!(TaggedVec::<usize, u32>::read_npy(bytes.as_slice()).is_err());
+
+        // Written by `numpy.save(file, numpy.array([1, 2], dtype=">u2"))`, with a shortened header.
+        let mut big_endian =
+            b"\x93NUMPY\x01\x00\x3a\x00{'descr': '>u2', 'fortran_order': False, 'shape': (2,), }\n"
+                .to_vec();
+        big_endian.extend_from_slice(&[0, 1, 0, 2]);
+        assert_eq!(
+            TaggedVec::<usize, u16>::read_npy(big_endian.as_slice()).unwrap(),
+            [1, 2]
+        );
+
+        let too_large = b"\x93NUMPY\x01\x00\x4d\x00{'descr': '<u2', 'fortran_order': False, 'shape': (18446744073709551615,), }\n";
+        let error = TaggedVec::<usize, u16>::read_npy(too_large.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
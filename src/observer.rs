@@ -0,0 +1,244 @@
+//! A `TaggedVec` wrapper that reports structural changes to an observer.
+
+use std::ops::Deref;
+
+use crate::TaggedVec;
+
+/// Receives notifications about structural changes of an [`ObservedTaggedVec`].
+///
+/// All methods have empty default implementations, so implementors only need to override the notifications they care about.
+pub trait TaggedVecObserver<Index, Value> {
+    /// Called after `value` was inserted at `index`.
+    fn on_insert(&mut self, index: Index, value: &Value) {
+        let _ = (index, value);
+    }
+
+    /// Called right before `value` is removed from `index`.
+    fn on_remove(&mut self, index: Index, value: &Value) {
+        let _ = (index, value);
+    }
+
+    /// Called when the value at index `from` moves to index `to`.
+    fn on_move(&mut self, from: Index, to: Index) {
+        let _ = (from, to);
+    }
+}
+
+/// A [`TaggedVec`] that notifies a [`TaggedVecObserver`] about all insertions, removals and moves of its values.
+///
+/// This allows to keep secondary structures, such as spatial indexes or caches, synchronised with the vector.
+/// Read access to the underlying `TaggedVec` is available via [`Deref`].
+pub struct ObservedTaggedVec<Index, Value, Observer> {
+    vec: TaggedVec<Index, Value>,
+    observer: Observer,
+}
+
+impl<Index, Value, Observer: TaggedVecObserver<Index, Value>>
+    ObservedTaggedVec<Index, Value, Observer>
+{
+    /// Creates a new empty `ObservedTaggedVec` reporting to the given observer.
+    pub fn new(observer: Observer) -> Self {
+        Self {
+            vec: TaggedVec::new(),
+            observer,
+        }
+    }
+
+    /// Returns a reference to the observer.
+    pub fn observer(&self) -> &Observer {
+        &self.observer
+    }
+
+    /// Returns a mutable reference to the observer.
+    pub fn observer_mut(&mut self) -> &mut Observer {
+        &mut self.observer
+    }
+
+    /// Consumes the `ObservedTaggedVec`, returning the underlying `TaggedVec` and the observer.
+    pub fn into_parts(self) -> (TaggedVec<Index, Value>, Observer) {
+        (self.vec, self.observer)
+    }
+
+    /// Inserts the given value at the back of the vector, returning its index.
+    ///
+    /// Reports [`TaggedVecObserver::on_insert`].
+    pub fn push(&mut self, value: Value) -> Index
+    where
+        Index: From<usize> + Copy,
+    {
+        let index = self.vec.push(value);
+        self.observer
+            .on_insert(index, self.vec.vec.last().expect("value was just pushed"));
+        index
+    }
+
+    /// Removes the value at the back of the vector and returns it with its index.
+    ///
+    /// Reports [`TaggedVecObserver::on_remove`].
+    pub fn pop(&mut self) -> Option<(Index, Value)>
+    where
+        Index: From<usize> + Copy,
+    {
+        let (index, value) = self.vec.pop()?;
+        self.observer.on_remove(index, &value);
+        Some((index, value))
+    }
+
+    /// Inserts the given `value` at position `index`, shifting all existing values in range `index..` one position to the right.
+    ///
+    /// Reports [`TaggedVecObserver::on_move`] for each shifted value, starting from the back, followed by [`TaggedVecObserver::on_insert`].
+    pub fn insert(&mut self, index: Index, value: Value)
+    where
+        Index: From<usize> + Into<usize>,
+    {
+        let index: usize = index.into();
+        self.vec.vec.insert(index, value);
+        for from in (index..self.vec.len() - 1).rev() {
+            self.observer.on_move(from.into(), (from + 1).into());
+        }
+        self.observer.on_insert(index.into(), &self.vec.vec[index]);
+    }
+
+    /// Removes and returns the value at position `index`, shifting all values after it one position to the left.
+    ///
+    /// Reports [`TaggedVecObserver::on_remove`], followed by [`TaggedVecObserver::on_move`] for each shifted value, starting from the front.
+    /// Panics if the index is out of bounds.
+    pub fn remove(&mut self, index: Index) -> Value
+    where
+        Index: From<usize> + Into<usize>,
+    {
+        let index: usize = index.into();
+        assert!(index < self.vec.len(), "index {index} out of bounds");
+        self.observer.on_remove(index.into(), &self.vec.vec[index]);
+        let value = self.vec.vec.remove(index);
+        for to in index..self.vec.len() {
+            self.observer.on_move((to + 1).into(), to.into());
+        }
+        value
+    }
+
+    /// Removes and returns the value at position `index`, replacing it with the last value.
+    ///
+    /// Reports [`TaggedVecObserver::on_remove`], followed by [`TaggedVecObserver::on_move`] of the last value if it was not the removed one.
+    /// Panics if the index is out of bounds.
+    pub fn swap_remove(&mut self, index: Index) -> Value
+    where
+        Index: From<usize> + Into<usize>,
+    {
+        let index: usize = index.into();
+        assert!(index < self.vec.len(), "index {index} out of bounds");
+        self.observer.on_remove(index.into(), &self.vec.vec[index]);
+        let value = self.vec.vec.swap_remove(index);
+        let last = self.vec.len();
+        if index != last {
+            self.observer.on_move(last.into(), index.into());
+        }
+        value
+    }
+
+    /// Retains only the values specified by the predicate.
+    ///
+    /// Reports [`TaggedVecObserver::on_remove`] for each removed value and [`TaggedVecObserver::on_move`] for each retained value whose index changes, in the original order.
+    pub fn retain(&mut self, mut f: impl FnMut(&Value) -> bool)
+    where
+        Index: From<usize>,
+    {
+        let observer = &mut self.observer;
+        let mut old_index = 0;
+        let mut new_index = 0;
+        self.vec.vec.retain(|value| {
+            let retain = f(value);
+            if retain {
+                if old_index != new_index {
+                    observer.on_move(old_index.into(), new_index.into());
+                }
+                new_index += 1;
+            } else {
+                observer.on_remove(old_index.into(), value);
+            }
+            old_index += 1;
+            retain
+        });
+    }
+}
+
+impl<Index, Value, Observer> Deref for ObservedTaggedVec<Index, Value, Observer> {
+    type Target = TaggedVec<Index, Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ObservedTaggedVec, TaggedVecObserver};
+
+    #[derive(Default)]
+    struct Log(Vec<String>);
+
+    impl TaggedVecObserver<usize, char> for Log {
+        fn on_insert(&mut self, index: usize, value: &char) {
+            self.0.push(format!("insert {index} {value}"));
+        }
+
+        fn on_remove(&mut self, index: usize, value: &char) {
+            self.0.push(format!("remove {index} {value}"));
+        }
+
+        fn on_move(&mut self, from: usize, to: usize) {
+            self.0.push(format!("move {from} {to}"));
+        }
+    }
+
+    #[test]
+    fn observed_mutations() {
+        let mut vec = ObservedTaggedVec::<usize, char, _>::new(Log::default());
+        vec.push('a');
+        vec.push('b');
+        vec.insert(0, 'c');
+        vec.retain(|value| *value != 'a');
+        vec.pop();
+
+        assert_eq!(vec.as_untagged_slice(), &['c']);
+        assert_eq!(
+            vec.into_parts().1.0,
+            [
+                "insert 0 a",
+                "insert 1 b",
+                "move 1 2",
+                "move 0 1",
+                "insert 0 c",
+                "remove 1 a",
+                "move 2 1",
+                "remove 1 b",
+            ]
+        );
+    }
+
+    #[test]
+    fn observed_removals() {
+        let mut vec = ObservedTaggedVec::<usize, char, _>::new(Log::default());
+        for value in ['a', 'b', 'c', 'd', 'e'] {
+            vec.push(value);
+        }
+        vec.observer_mut().0.clear();
+
+        assert_eq!(vec.remove(1), 'b');
+        assert_eq!(vec.swap_remove(0), 'a');
+        assert_eq!(vec.swap_remove(2), 'd');
+        assert_eq!(vec.as_untagged_slice(), &['e', 'c']);
+        assert_eq!(
+            vec.into_parts().1.0,
+            [
+                "remove 1 b",
+                "move 2 1",
+                "move 3 2",
+                "move 4 3",
+                "remove 0 a",
+                "move 3 0",
+                "remove 2 d",
+            ]
+        );
+    }
+}
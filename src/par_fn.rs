@@ -0,0 +1,37 @@
+//! Parallel construction of `TaggedVec`s using [`rayon`].
+
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::TaggedVec;
+
+impl<Index, Value: Send> TaggedVec<Index, Value> {
+    /// Creates a new `TaggedVec` of the given length, where the value at each index is computed by `f` from the index.
+    ///
+    /// The values are computed in parallel on the global rayon thread pool, and assembled in order of their indices.
+    pub fn from_par_fn(len: usize, f: impl Fn(Index) -> Value + Sync + Send) -> Self
+    where
+        Index: From<usize>,
+    {
+        (0..len)
+            .into_par_iter()
+            .map(|index| f(index.into()))
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TaggedVec;
+
+    #[test]
+    fn from_par_fn() {
+        let vec = TaggedVec::<usize, _>::from_par_fn(10_000, |index| index * 2);
+        assert_eq!(vec.len(), 10_000);
+        assert!(
+            vec.iter_values()
+                .enumerate()
+                .all(|(index, value)| *value == index * 2)
+        );
+    }
+}
@@ -0,0 +1,110 @@
+//! Data parallelism over `TaggedVec`s built on [`std::thread::scope`].
+
+use std::thread;
+
+use crate::{TaggedRange, TaggedVec};
+
+impl<Index, Value: Send> TaggedVec<Index, Value> {
+    /// Calls `f` on each entry, distributing the work over `num_threads` scoped threads.
+    ///
+    /// The vector is split into `num_threads` contiguous parts of roughly equal length like in [`Self::split_into_mut`], and each part is processed by one thread.
+    /// Panics if `num_threads` is zero, or if `f` panics.
+    pub fn par_for_each_with_index(
+        &mut self,
+        num_threads: usize,
+        f: impl Fn(Index, &mut Value) + Sync,
+    ) where
+        Index: From<usize>,
+    {
+        let f = &f;
+        let mut rest = self.vec.as_mut_slice();
+        thread::scope(|scope| {
+            for range in TaggedRange::<usize>::new(0, rest.len()).split_into(num_threads) {
+                let (part, remainder) = std::mem::take(&mut rest).split_at_mut(range.len());
+                rest = remainder;
+                let base_index = range.start;
+                scope.spawn(move || {
+                    for (offset, value) in part.iter_mut().enumerate() {
+                        f((base_index + offset).into(), value);
+                    }
+                });
+            }
+        });
+    }
+
+    /// Creates a new `TaggedVec` of the given length, where the value at each index is computed by `f` from the index, distributing the work over `num_threads` scoped threads.
+    ///
+    /// The indices are grouped into consecutive chunks of `chunk_len` indices, and the chunks are split into `num_threads` contiguous parts of roughly equal length like in [`Self::par_for_each_with_index`], each initialised by one thread.
+    /// If `len` is a multiple of `chunk_len * num_threads`, then the parts are exactly those processed by the threads of [`Self::par_for_each_with_index`].
+    /// Since memory pages are usually placed on the NUMA node of the thread that touches them first, later processing each part on a thread of the same node avoids remote memory accesses.
+    /// Choosing `chunk_len` as a multiple of the number of values per page avoids pages shared between threads.
+    /// Panics if `num_threads` or `chunk_len` is zero, or if `f` panics.
+    pub fn from_value_fn_parallel_chunks(
+        len: usize,
+        num_threads: usize,
+        chunk_len: usize,
+        f: impl Fn(Index) -> Value + Sync,
+    ) -> Self
+    where
+        Index: From<usize>,
+    {
+        assert!(num_threads > 0, "number of threads must be non-zero");
+        assert!(chunk_len > 0, "chunk length must be non-zero");
+
+        let f = &f;
+        let mut vec = Vec::with_capacity(len);
+        let mut rest = &mut vec.spare_capacity_mut()[..len];
+        thread::scope(|scope| {
+            for chunks in
+                TaggedRange::<usize>::new(0, len.div_ceil(chunk_len)).split_into(num_threads)
+            {
+                let base_index = (chunks.start * chunk_len).min(len);
+                let part_len = (chunks.end * chunk_len).min(len) - base_index;
+                let (part, remainder) = std::mem::take(&mut rest).split_at_mut(part_len);
+                rest = remainder;
+                scope.spawn(move || {
+                    for (offset, value) in part.iter_mut().enumerate() {
+                        value.write(f((base_index + offset).into()));
+                    }
+                });
+            }
+        });
+        // All values were initialised, since the scope only returns if no thread panicked.
+        unsafe {
+            vec.set_len(len);
+        }
+        vec.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TaggedVec;
+
+    #[test]
+    fn par_for_each_with_index() {
+        let mut vec = TaggedVec::<usize, _>::from(vec![0; 1000]);
+        vec.par_for_each_with_index(4, |index, value| *value = index * 2);
+        assert!(vec.iter(..).all(|(index, value)| *value == index * 2));
+    }
+
+    #[test]
+    fn from_value_fn_parallel_chunks() {
+        let vec =
+            TaggedVec::<usize, _>::from_value_fn_parallel_chunks(1000, 3, 64, |index| index * 2);
+        assert_eq!(vec.len(), 1000);
+        assert!(vec.iter(..).all(|(index, value)| *value == index * 2));
+
+        let threads = TaggedVec::<usize, _>::from_value_fn_parallel_chunks(12, 3, 2, |_| {
+            std::thread::current().id()
+        });
+        for part in threads.as_untagged_slice().chunks(4) {
+            assert!(part.iter().all(|id| *id == part[0]));
+        }
+        assert_ne!(threads[0], threads[4]);
+        assert_ne!(threads[4], threads[8]);
+
+        let vec = TaggedVec::<usize, _>::from_value_fn_parallel_chunks(3, 4, 2, |index| index);
+        assert_eq!(vec, [0, 1, 2]);
+    }
+}
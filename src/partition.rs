@@ -0,0 +1,119 @@
+//! Splitting a `TaggedVec` into multiple `TaggedVec`s.
+
+use std::{collections::HashMap, hash::Hash};
+
+use crate::TaggedVec;
+
+/// A value of one of two types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Either<L, R> {
+    /// A value of the left type.
+    Left(L),
+    /// A value of the right type.
+    Right(R),
+}
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Consumes the vector and distributes its values into two new vectors according to `f`.
+    ///
+    /// Values for which `f` returns [`Either::Left`] are pushed to the first vector, and values for which it returns [`Either::Right`] to the second one, preserving their order.
+    /// Additionally returns a remap from each old index to the index of its value in the vector it was moved to.
+    #[allow(clippy::type_complexity)]
+    pub fn partition_map<LeftIndex, Left, RightIndex, Right>(
+        self,
+        mut f: impl FnMut(Index, Value) -> Either<Left, Right>,
+    ) -> (
+        TaggedVec<LeftIndex, Left>,
+        TaggedVec<RightIndex, Right>,
+        TaggedVec<Index, Either<LeftIndex, RightIndex>>,
+    )
+    where
+        Index: From<usize>,
+        LeftIndex: From<usize>,
+        RightIndex: From<usize>,
+    {
+        let mut left = TaggedVec::new();
+        let mut right = TaggedVec::new();
+        let remap = self
+            .vec
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| match f(index.into(), value) {
+                Either::Left(value) => Either::Left(left.push(value)),
+                Either::Right(value) => Either::Right(right.push(value)),
+            })
+            .collect();
+        (left, right, remap)
+    }
+
+    /// Consumes the vector and distributes its values into one new vector per key, as computed by `f`, preserving their order.
+    ///
+    /// Additionally returns a remap from each old index to the key and the index of its value in the vector of that key.
+    #[allow(clippy::type_complexity)]
+    pub fn split_by_key<NewIndex, K: Eq + Hash + Clone>(
+        self,
+        mut f: impl FnMut(Index, &Value) -> K,
+    ) -> (
+        HashMap<K, TaggedVec<NewIndex, Value>>,
+        TaggedVec<Index, (K, NewIndex)>,
+    )
+    where
+        Index: From<usize>,
+        NewIndex: From<usize>,
+    {
+        let mut groups: HashMap<K, TaggedVec<NewIndex, Value>> = HashMap::new();
+        let remap = self
+            .vec
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let key = f(index.into(), &value);
+                let new_index = groups.entry(key.clone()).or_default().push(value);
+                (key, new_index)
+            })
+            .collect();
+        (groups, remap)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Either;
+    use crate::TaggedVec;
+
+    #[test]
+    fn partition_map() {
+        let vec = TaggedVec::<usize, _>::from(vec!["1", "a", "2", "b"]);
+        let (numbers, words, remap): (TaggedVec<usize, u32>, TaggedVec<usize, String>, _) = vec
+            .partition_map(|_, value| match value.parse() {
+                Ok(number) => Either::Left(number),
+                Err(_) => Either::Right(value.to_uppercase()),
+            });
+
+        assert_eq!(numbers, [1, 2]);
+        assert_eq!(words, ["A", "B"]);
+        assert_eq!(
+            remap,
+            [
+                Either::Left(0),
+                Either::Right(0),
+                Either::Left(1),
+                Either::Right(1)
+            ]
+        );
+    }
+
+    #[test]
+    fn split_by_key() {
+        let vec = TaggedVec::<usize, _>::from(vec![1, 2, 3, 4, 5]);
+        let (groups, remap) = vec.split_by_key::<usize, _>(|_, value| value % 2 == 0);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[&false], [1, 3, 5]);
+        assert_eq!(groups[&true], [2, 4]);
+        assert_eq!(
+            remap,
+            [(false, 0), (true, 0), (false, 1), (true, 1), (false, 2)]
+        );
+    }
+}
@@ -0,0 +1,213 @@
+//! A `TaggedVec` that writes its modifications back to a file.
+
+use std::{
+    fs::{File, OpenOptions},
+    io::{Seek, SeekFrom, Write},
+    mem,
+    ops::{Deref, Range},
+    path::Path,
+};
+
+use crate::TaggedVec;
+
+/// A [`TaggedVec`] backed by a file in the format of [`TaggedVec::write_binary`].
+///
+/// Modifications made via the typed-index API are tracked, and written back to the file by [`Self::flush`], [`Self::sync`], or when the `PersistentFileTaggedVec` is dropped.
+/// Only the modified region of the file is rewritten.
+/// Read access to the underlying `TaggedVec` is available via [`Deref`].
+pub struct PersistentFileTaggedVec<Index, Value: Copy> {
+    file: File,
+    vec: TaggedVec<Index, Value>,
+    /// The range of values that was modified since the last flush.
+    dirty: Option<Range<usize>>,
+    /// The length of the vector stored in the file.
+    file_len: usize,
+}
+
+impl<Index, Value: Copy> PersistentFileTaggedVec<Index, Value> {
+    /// Creates a new empty `PersistentFileTaggedVec` at the given path, truncating any existing file.
+    pub fn create(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        let mut result = Self {
+            file,
+            vec: TaggedVec::new(),
+            dirty: None,
+            file_len: usize::MAX,
+        };
+        result.flush()?;
+        Ok(result)
+    }
+
+    /// Opens an existing file written by [`TaggedVec::write_binary`] or by a `PersistentFileTaggedVec`.
+    ///
+    /// The same restrictions as for [`TaggedVec::read_binary`] apply.
+    pub fn open(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let mut file = OpenOptions::new().read(true).write(true).open(path)?;
+        let vec = TaggedVec::read_binary(&mut file)?;
+        let file_len = vec.len();
+        Ok(Self {
+            file,
+            vec,
+            dirty: None,
+            file_len,
+        })
+    }
+
+    /// Inserts the given value at the back of the vector, returning its index.
+    pub fn push(&mut self, value: Value) -> Index
+    where
+        Index: From<usize>,
+    {
+        self.mark_dirty(self.vec.len()..self.vec.len() + 1);
+        self.vec.push(value)
+    }
+
+    /// Removes the value at the back of the vector and returns it with its index.
+    pub fn pop(&mut self) -> Option<(Index, Value)>
+    where
+        Index: From<usize>,
+    {
+        self.vec.pop()
+    }
+
+    /// Inserts the given `value` at position `index`, shifting all existing values in range `index..` one position to the right.
+    pub fn insert(&mut self, index: Index, value: Value)
+    where
+        Index: Into<usize>,
+    {
+        let index = index.into();
+        self.vec.vec.insert(index, value);
+        self.mark_dirty(index..self.vec.len());
+    }
+
+    /// Returns a mutable reference to the value at the given index, or `None` if the index is out of bounds.
+    ///
+    /// The value is written back to the file on the next flush, even if it is not modified.
+    pub fn get_mut(&mut self, index: Index) -> Option<&mut Value>
+    where
+        Index: Into<usize>,
+    {
+        let index = index.into();
+        if index < self.vec.len() {
+            self.mark_dirty(index..index + 1);
+        }
+        self.vec.vec.get_mut(index)
+    }
+
+    /// Writes all modifications to the file.
+    pub fn flush(&mut self) -> std::io::Result<()> {
+        let value_size = mem::size_of::<Value>();
+        let header_size = mem::size_of::<usize>() as u64;
+
+        // The dirty range is only cleared after it was written, so a failed flush can be retried.
+        if let Some(dirty) = self.dirty.clone() {
+            let dirty = dirty.start..dirty.end.min(self.vec.len());
+            if !dirty.is_empty() {
+                let data = &self.vec.vec[dirty.clone()];
+                let data: &[u8] = unsafe {
+                    std::slice::from_raw_parts(data.as_ptr() as *const u8, mem::size_of_val(data))
+                };
+                self.file.seek(SeekFrom::Start(
+                    header_size + (dirty.start * value_size) as u64,
+                ))?;
+                self.file.write_all(data)?;
+            }
+            self.dirty = None;
+        }
+
+        if self.file_len != self.vec.len() {
+            self.file.seek(SeekFrom::Start(0))?;
+            self.file.write_all(&self.vec.len().to_ne_bytes())?;
+            self.file
+                .set_len(header_size + (self.vec.len() * value_size) as u64)?;
+            self.file_len = self.vec.len();
+        }
+
+        self.file.flush()
+    }
+
+    /// Writes all modifications to the file and waits until they have reached the storage device.
+    pub fn sync(&mut self) -> std::io::Result<()> {
+        self.flush()?;
+        self.file.sync_all()
+    }
+
+    fn mark_dirty(&mut self, range: Range<usize>) {
+        self.dirty = Some(match self.dirty.take() {
+            Some(dirty) => dirty.start.min(range.start)..dirty.end.max(range.end),
+            None => range,
+        });
+    }
+}
+
+impl<Index, Value: Copy> Deref for PersistentFileTaggedVec<Index, Value> {
+    type Target = TaggedVec<Index, Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+impl<Index: Into<usize>, Value: Copy> std::ops::IndexMut<Index>
+    for PersistentFileTaggedVec<Index, Value>
+{
+    fn index_mut(&mut self, index: Index) -> &mut Self::Output {
+        self.get_mut(index).expect("index out of bounds")
+    }
+}
+
+impl<Index: Into<usize>, Value: Copy> std::ops::Index<Index>
+    for PersistentFileTaggedVec<Index, Value>
+{
+    type Output = Value;
+
+    fn index(&self, index: Index) -> &Self::Output {
+        &self.vec[index]
+    }
+}
+
+impl<Index, Value: Copy> Drop for PersistentFileTaggedVec<Index, Value> {
+    fn drop(&mut self) {
+        // Errors cannot be reported here, call `flush` explicitly to handle them.
+        let _ = self.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use super::PersistentFileTaggedVec;
+    use crate::TaggedVec;
+
+    #[test]
+    fn persistent_file() {
+        let path = std::env::temp_dir().join(format!(
+            "tagged-vec-persistent-file-{}.bin",
+            std::process::id()
+        ));
+
+        let mut vec = PersistentFileTaggedVec::<usize, u32>::create(&path).unwrap();
+        vec.push(1);
+        vec.push(2);
+        vec.push(3);
+        drop(vec);
+
+        let mut vec = PersistentFileTaggedVec::<usize, u32>::open(&path).unwrap();
+        assert_eq!(vec.as_untagged_slice(), &[1, 2, 3]);
+        vec[1] = 5;
+        vec.pop();
+        vec.sync().unwrap();
+
+        let read_vec = TaggedVec::<usize, u32>::read_binary(File::open(&path).unwrap()).unwrap();
+        assert_eq!(read_vec.as_untagged_slice(), &[1, 5]);
+
+        drop(vec);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,62 @@
+//! Marker traits for value types that can be read from and written as raw bytes.
+
+/// Marker for "plain old data" types that can be safely reinterpreted from arbitrary bytes.
+///
+/// # Safety
+///
+/// Implementors must be [`Copy`], contain no padding bytes and no pointers, and every bit pattern must be a valid value of the type.
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+    ($($type:ty),*) => {
+        $(
+            unsafe impl Pod for $type {}
+        )*
+    };
+}
+
+impl_pod!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+/// A [`Pod`] type whose byte order can be reversed, which allows to read values written on a machine with a different endianness.
+///
+/// It is not implemented for `usize` and `isize`, since their size depends on the machine.
+pub trait SwapBytes: Pod {
+    /// Reverses the byte order of the value.
+    fn swap_bytes(self) -> Self;
+}
+
+macro_rules! impl_swap_bytes_int {
+    ($($type:ty),*) => {
+        $(
+            impl SwapBytes for $type {
+                fn swap_bytes(self) -> Self {
+                    <$type>::swap_bytes(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_swap_bytes_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl SwapBytes for f32 {
+    fn swap_bytes(self) -> Self {
+        Self::from_bits(self.to_bits().swap_bytes())
+    }
+}
+
+impl SwapBytes for f64 {
+    fn swap_bytes(self) -> Self {
+        Self::from_bits(self.to_bits().swap_bytes())
+    }
+}
+
+impl<T: SwapBytes, const N: usize> SwapBytes for [T; N] {
+    fn swap_bytes(self) -> Self {
+        self.map(T::swap_bytes)
+    }
+}
@@ -0,0 +1,148 @@
+//! A binary format for `TaggedVec` that is independent of the endianness and pointer size of the machine.
+
+use std::{
+    io::{Error, ErrorKind, Read, Write},
+    mem,
+};
+
+use crate::{SwapBytes, TaggedVec};
+
+/// The magic number at the start of the portable binary format.
+const MAGIC: [u8; 8] = *b"TAGGEDVC";
+/// The version of the portable binary format.
+const VERSION: u16 = 1;
+/// The size of the header of the portable binary format.
+pub(crate) const HEADER_SIZE: usize = 24;
+/// The offset of the little-endian `u64` length in the header of the portable binary format.
+pub(crate) const LEN_OFFSET: usize = 16;
+const LITTLE_ENDIAN: u8 = 0;
+const BIG_ENDIAN: u8 = 1;
+const NATIVE_ENDIAN: u8 = if cfg!(target_endian = "big") {
+    BIG_ENDIAN
+} else {
+    LITTLE_ENDIAN
+};
+
+impl<Index, Value: SwapBytes> TaggedVec<Index, Value> {
+    /// Writes the `TaggedVec` in a binary format that can be read on any machine with [`Self::read_binary_portable`].
+    ///
+    /// The format consists of a header with a magic number, the format version, an endianness marker followed by a reserved byte, the size of `Value` and the length as little-endian `u64`.
+    /// The header is followed by the bytes of the values in native endianness, which are byte-swapped when read on a machine with a different endianness.
+    pub fn write_binary_portable(&self, mut writer: impl Write) -> std::io::Result<()> {
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&[NATIVE_ENDIAN, 0])?;
+        writer.write_all(&(mem::size_of::<Value>() as u32).to_le_bytes())?;
+        writer.write_all(&(self.len() as u64).to_le_bytes())?;
+        writer.write_all(Self::values_as_bytes(&self.vec))
+    }
+
+    /// Reads a `TaggedVec` written by [`Self::write_binary_portable`], possibly on a different machine.
+    ///
+    /// Fails with [`ErrorKind::InvalidData`] if the header is malformed, if the size of `Value` differs from the one stored in the header, or if the length does not fit into `usize`.
+    /// The length in the header is not trusted for allocations, so a corrupt header fails with [`ErrorKind::UnexpectedEof`] instead of allocating memory for data that does not exist.
+    pub fn read_binary_portable(mut reader: impl Read) -> std::io::Result<Self> {
+        let mut magic = [0; MAGIC.len()];
+        reader.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(invalid_data("not a portable tagged vector".to_string()));
+        }
+
+        let mut version = [0; 2];
+        reader.read_exact(&mut version)?;
+        let version = u16::from_le_bytes(version);
+        if version != VERSION {
+            return Err(invalid_data(format!(
+                "unsupported format version {version}"
+            )));
+        }
+
+        let mut endianness = [0; 2];
+        reader.read_exact(&mut endianness)?;
+        let swap = match endianness[0] {
+            LITTLE_ENDIAN | BIG_ENDIAN => endianness[0] != NATIVE_ENDIAN,
+            marker => return Err(invalid_data(format!("invalid endianness marker {marker}"))),
+        };
+
+        let mut value_size = [0; 4];
+        reader.read_exact(&mut value_size)?;
+        let value_size = u32::from_le_bytes(value_size);
+        if u64::from(value_size) != mem::size_of::<Value>() as u64 {
+            return Err(invalid_data(format!(
+                "value size mismatch: expected {}, but found {value_size}",
+                mem::size_of::<Value>()
+            )));
+        }
+
+        let mut len = [0; 8];
+        reader.read_exact(&mut len)?;
+        let len = usize::try_from(u64::from_le_bytes(len))
+            .map_err(|_| invalid_data("length does not fit into usize".to_string()))?;
+        let bytes_len = len
+            .checked_mul(mem::size_of::<Value>())
+            .ok_or_else(|| invalid_data(format!("length {len} is too large")))?;
+
+        let mut bytes = Vec::new();
+        reader.take(bytes_len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != bytes_len {
+            return Err(Error::from(ErrorKind::UnexpectedEof));
+        }
+        let mut values = Self::read_values(&mut bytes.as_slice(), len, |_| {})?;
+        if swap {
+            for value in &mut values {
+                *value = value.swap_bytes();
+            }
+        }
+        Ok(values.into())
+    }
+}
+
+fn invalid_data(message: String) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TaggedVec;
+
+    #[test]
+    fn portable_roundtrip() {
+        let vec = TaggedVec::<usize, u32>::from(vec![1, 0x01020304]);
+        let mut buffer = Vec::new();
+        vec.write_binary_portable(&mut buffer).unwrap();
+        assert_eq!(buffer.len(), 24 + 8);
+        assert_eq!(
+            TaggedVec::<usize, u32>::read_binary_portable(buffer.as_slice()).unwrap(),
+            vec
+        );
+        assert!(TaggedVec::<usize, u16>::read_binary_portable(buffer.as_slice()).is_err());
+        assert!(TaggedVec::<usize, u32>::read_binary_portable(&buffer[1..]).is_err());
+    }
+
+    #[test]
+    fn portable_foreign_endianness() {
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(b"TAGGEDVC");
+        buffer.extend_from_slice(&1u16.to_le_bytes());
+        let foreign_endianness = if cfg!(target_endian = "big") { 0 } else { 1 };
+        buffer.extend_from_slice(&[foreign_endianness, 0]);
+        buffer.extend_from_slice(&2u32.to_le_bytes());
+        buffer.extend_from_slice(&1u64.to_le_bytes());
+        buffer.extend_from_slice(&0x0102u16.swap_bytes().to_ne_bytes());
+
+        let vec = TaggedVec::<usize, u16>::read_binary_portable(buffer.as_slice()).unwrap();
+        assert_eq!(vec, [0x0102]);
+    }
+
+    #[test]
+    fn portable_forged_length() {
+        let mut buffer = Vec::new();
+        TaggedVec::<usize, u64>::from(vec![1])
+            .write_binary_portable(&mut buffer)
+            .unwrap();
+        for len in [1 << 40, u64::MAX / 2] {
+            buffer[16..24].copy_from_slice(&len.to_le_bytes());
+            assert!(TaggedVec::<usize, u64>::read_binary_portable(buffer.as_slice()).is_err());
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Index-aware predicate queries over a `TaggedVec`.
+
+use crate::TaggedVec;
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Returns the number of entries for which `f` returns `true`.
+    pub fn count_matching(&self, mut f: impl FnMut(Index, &Value) -> bool) -> usize
+    where
+        Index: From<usize>,
+    {
+        self.vec
+            .iter()
+            .enumerate()
+            .filter(|(index, value)| f((*index).into(), value))
+            .count()
+    }
+
+    /// Returns `true` if `f` returns `true` for any entry.
+    ///
+    /// Stops at the first entry for which `f` returns `true`.
+    pub fn any_with_index(&self, mut f: impl FnMut(Index, &Value) -> bool) -> bool
+    where
+        Index: From<usize>,
+    {
+        self.vec
+            .iter()
+            .enumerate()
+            .any(|(index, value)| f(index.into(), value))
+    }
+
+    /// Returns `true` if `f` returns `true` for all entries.
+    ///
+    /// Stops at the first entry for which `f` returns `false`.
+    pub fn all_with_index(&self, mut f: impl FnMut(Index, &Value) -> bool) -> bool
+    where
+        Index: From<usize>,
+    {
+        self.vec
+            .iter()
+            .enumerate()
+            .all(|(index, value)| f(index.into(), value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TaggedVec;
+
+    #[test]
+    fn predicates() {
+        let vec = TaggedVec::<usize, _>::from(vec![0, 1, 5, 3]);
+        assert_eq!(vec.count_matching(|index, value| index == *value), 3);
+        assert!(vec.any_with_index(|index, value| index > 0 && *value > 4));
+        assert!(!vec.all_with_index(|index, value| index == *value));
+        assert!(vec.all_with_index(|index, value| index <= *value));
+    }
+}
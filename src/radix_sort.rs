@@ -0,0 +1,119 @@
+//! Radix sorting for `TaggedVec`s of integer-like values.
+
+use std::mem;
+
+use crate::TaggedVec;
+
+/// A value that can be sorted by [`TaggedVec::sort_unstable_radix`].
+///
+/// The ordering of the values must correspond to the lexicographic ordering of their radix bytes, starting from the most significant byte.
+pub trait RadixKey: Copy {
+    /// The number of radix bytes of a value.
+    const BYTES: usize;
+
+    /// Returns the radix byte with the given significance, where `0` is the least significant byte.
+    fn radix_byte(&self, byte: usize) -> u8;
+}
+
+macro_rules! impl_radix_key_unsigned {
+    ($($type:ty),*) => {
+        $(
+            impl RadixKey for $type {
+                const BYTES: usize = mem::size_of::<$type>();
+
+                fn radix_byte(&self, byte: usize) -> u8 {
+                    (*self >> (8 * byte)) as u8
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_radix_key_signed {
+    ($($type:ty => $unsigned:ty),*) => {
+        $(
+            impl RadixKey for $type {
+                const BYTES: usize = mem::size_of::<$type>();
+
+                fn radix_byte(&self, byte: usize) -> u8 {
+                    // Flipping the sign bit orders negative values before positive values.
+                    let unsigned = (*self as $unsigned) ^ (1 << (<$unsigned>::BITS - 1));
+                    (unsigned >> (8 * byte)) as u8
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_key_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_radix_key_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+impl<Index, Value: RadixKey> TaggedVec<Index, Value> {
+    /// Sorts the values with a least-significant-digit radix sort in `O(n * Value::BYTES)` time.
+    ///
+    /// For large vectors of integers, this is considerably faster than comparison-based sorting.
+    /// It allocates a buffer of the same size as the vector.
+    pub fn sort_unstable_radix(&mut self) {
+        radix_sort_by_key(&mut self.vec, |value| *value);
+    }
+
+    /// Like [`Self::sort_unstable_radix`], but additionally returns a remap from each old index to the new index of its value.
+    pub fn sort_unstable_radix_with_remap(&mut self) -> TaggedVec<Index, Index>
+    where
+        Index: From<usize>,
+    {
+        let mut entries: Vec<_> = self.vec.iter().copied().zip(0..).collect();
+        radix_sort_by_key(&mut entries, |(value, _)| *value);
+
+        let mut remap = vec![0; entries.len()];
+        for (new_index, (value, old_index)) in entries.into_iter().enumerate() {
+            self.vec[new_index] = value;
+            remap[old_index] = new_index;
+        }
+        remap.into_iter().map(Index::from).collect()
+    }
+}
+
+fn radix_sort_by_key<T: Copy, Key: RadixKey>(items: &mut Vec<T>, key: impl Fn(&T) -> Key) {
+    let mut buffer = items.clone();
+
+    for byte in 0..Key::BYTES {
+        let mut offsets = [0; 256];
+        for item in items.iter() {
+            offsets[usize::from(key(item).radix_byte(byte))] += 1;
+        }
+        if offsets.contains(&items.len()) {
+            // All items share this byte, so this pass would not change the order.
+            continue;
+        }
+
+        let mut offset = 0;
+        for count in &mut offsets {
+            offset += mem::replace(count, offset);
+        }
+        for item in items.iter() {
+            let bucket = &mut offsets[usize::from(key(item).radix_byte(byte))];
+            buffer[*bucket] = *item;
+            *bucket += 1;
+        }
+
+        mem::swap(items, &mut buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TaggedVec;
+
+    #[test]
+    fn sort_unstable_radix() {
+        let mut vec = TaggedVec::<usize, u32>::from(vec![300, 5, 70_000, 5, 0, 256]);
+        vec.sort_unstable_radix();
+        assert_eq!(vec, [0, 5, 5, 256, 300, 70_000]);
+
+        let mut vec = TaggedVec::<usize, i16>::from(vec![3, -1, i16::MIN, 0, i16::MAX, -300]);
+        let remap = vec.sort_unstable_radix_with_remap();
+        assert_eq!(vec, [i16::MIN, -300, -1, 0, 3, i16::MAX]);
+        assert_eq!(remap, [4, 2, 0, 3, 5, 1]);
+    }
+}
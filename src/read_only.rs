@@ -0,0 +1,130 @@
+//! A trait-object friendly read-only interface for containers indexed by a typed index.
+
+use crate::{EntryIterator, FrozenTaggedVec, TaggedSlice, TaggedVec, TaggedVecView};
+
+/// Read access to a sequence of values addressed by `Index`.
+///
+/// The trait is `dyn`-compatible, so functions can accept a `&dyn TaggedReadOnly<Index, Value>` instead of being generic over the container type.
+pub trait TaggedReadOnly<Index, Value> {
+    /// Returns the number of values.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no values.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the value at the given index, or `None` if it is out of bounds.
+    fn get(&self, index: Index) -> Option<&Value>;
+
+    /// Returns a boxed iterator over all entries in order of their indices.
+    fn iter(&self) -> Box<dyn Iterator<Item = (Index, &Value)> + '_>;
+}
+
+impl<Index: From<usize> + Into<usize>, Value> TaggedReadOnly<Index, Value>
+    for TaggedVec<Index, Value>
+{
+    fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    fn get(&self, index: Index) -> Option<&Value> {
+        self.vec.get(index.into())
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (Index, &Value)> + '_> {
+        Box::new(EntryIterator::new(&self.vec))
+    }
+}
+
+impl<Index: From<usize> + Into<usize>, Value> TaggedReadOnly<Index, Value>
+    for TaggedSlice<Index, Value>
+{
+    fn len(&self) -> usize {
+        TaggedSlice::len(self)
+    }
+
+    fn get(&self, index: Index) -> Option<&Value> {
+        TaggedSlice::get(self, index)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (Index, &Value)> + '_> {
+        Box::new(TaggedSlice::iter(self))
+    }
+}
+
+impl<Index: From<usize> + Into<usize>, Value> TaggedReadOnly<Index, Value>
+    for TaggedVecView<'_, Index, Value>
+{
+    fn len(&self) -> usize {
+        TaggedVecView::len(self)
+    }
+
+    fn get(&self, index: Index) -> Option<&Value> {
+        TaggedVecView::get(self, index)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (Index, &Value)> + '_> {
+        Box::new(TaggedVecView::iter(self))
+    }
+}
+
+impl<Index: From<usize> + Into<usize>, Value> TaggedReadOnly<Index, Value>
+    for FrozenTaggedVec<Index, Value>
+{
+    fn len(&self) -> usize {
+        TaggedReadOnly::len(&**self)
+    }
+
+    fn get(&self, index: Index) -> Option<&Value> {
+        TaggedReadOnly::get(&**self, index)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (Index, &Value)> + '_> {
+        TaggedReadOnly::iter(&**self)
+    }
+}
+
+#[cfg(feature = "binary-io")]
+impl<Index: From<usize> + Into<usize>, Value> TaggedReadOnly<Index, Value>
+    for crate::TaggedSliceView<'_, Index, Value>
+{
+    fn len(&self) -> usize {
+        crate::TaggedSliceView::len(self)
+    }
+
+    fn get(&self, index: Index) -> Option<&Value> {
+        crate::TaggedSliceView::get(self, index)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (Index, &Value)> + '_> {
+        Box::new(crate::TaggedSliceView::iter(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TaggedReadOnly;
+    use crate::TaggedVec;
+
+    fn sum(values: &dyn TaggedReadOnly<usize, u32>) -> u32 {
+        values
+            .iter()
+            .map(|(index, value)| index as u32 * value)
+            .sum()
+    }
+
+    #[test]
+    fn dyn_read_only() {
+        let vec = TaggedVec::<usize, u32>::from(vec![1, 2, 3]);
+        let containers: [&dyn TaggedReadOnly<usize, u32>; 3] =
+            [&vec, &vec.view_range(1..), &vec.clone().freeze()];
+
+        assert_eq!(containers.map(|container| container.len()), [3, 2, 3]);
+        assert_eq!(
+            containers.map(|container| container.get(0)),
+            [Some(&1), None, Some(&1)]
+        );
+        assert_eq!(containers.map(sum), [8, 8, 8]);
+    }
+}
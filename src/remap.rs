@@ -0,0 +1,352 @@
+//! Reordering and compacting operations that report where each value moved.
+
+use crate::{TaggedBitSet, TaggedVec};
+
+/// The relocation of a value by [`TaggedVec::swap_remove_with_remap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MovedFrom<Index> {
+    /// The index of the value before it was moved.
+    pub from: Index,
+    /// The index of the value after it was moved.
+    pub to: Index,
+}
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Retains only the values specified by the predicate, like [`Self::retain`].
+    ///
+    /// Returns a remap from each old index to the new index of its value, or `None` if the value was removed.
+    /// This allows to update all references into this vector stored elsewhere in a single pass.
+    pub fn retain_with_remap(
+        &mut self,
+        mut f: impl FnMut(&Value) -> bool,
+    ) -> TaggedVec<Index, Option<Index>>
+    where
+        Index: From<usize>,
+    {
+        let mut remap = TaggedVec::with_capacity(self.len());
+        let mut new_len = 0;
+        self.vec.retain(|value| {
+            if f(value) {
+                remap.vec.push(Some(new_len.into()));
+                new_len += 1;
+                true
+            } else {
+                remap.vec.push(None);
+                false
+            }
+        });
+        remap
+    }
+
+    /// Removes the value at the given index and replaces it with the last value.
+    ///
+    /// Returns the removed value, as well as the relocation of the last value, or `None` if the removed value was the last one.
+    /// Panics if the index is out of bounds.
+    pub fn swap_remove_with_remap(&mut self, index: Index) -> (Value, Option<MovedFrom<Index>>)
+    where
+        Index: From<usize> + Into<usize>,
+    {
+        let index = index.into();
+        let value = self.vec.swap_remove(index);
+        let moved = (index < self.len()).then(|| MovedFrom {
+            from: self.len().into(),
+            to: index.into(),
+        });
+        (value, moved)
+    }
+
+    /// Retains only the values whose index is contained in the given set, in a single pass.
+    ///
+    /// Returns a remap like [`Self::retain_with_remap`].
+    pub fn retain_indices(
+        &mut self,
+        indices: &TaggedBitSet<Index>,
+    ) -> TaggedVec<Index, Option<Index>>
+    where
+        Index: From<usize>,
+    {
+        let mut index = 0;
+        self.retain_with_remap(|_| {
+            index += 1;
+            indices.contains_usize(index - 1)
+        })
+    }
+
+    /// Removes the values whose index is contained in the given set, in a single pass.
+    ///
+    /// Returns a remap like [`Self::retain_with_remap`].
+    pub fn remove_indices(
+        &mut self,
+        indices: &TaggedBitSet<Index>,
+    ) -> TaggedVec<Index, Option<Index>>
+    where
+        Index: From<usize>,
+    {
+        let mut index = 0;
+        self.retain_with_remap(|_| {
+            index += 1;
+            !indices.contains_usize(index - 1)
+        })
+    }
+
+    /// Reorders the vector such that all values for which `f` returns `true` precede all values for which it returns `false`.
+    ///
+    /// The partition is stable, i.e. the relative order of values within each part is preserved.
+    /// Returns the index of the first value of the second part (equal to the length if it is empty), as well as a remap from each old index to the new index of its value.
+    pub fn stable_partition_with_remap(
+        &mut self,
+        mut f: impl FnMut(&Value) -> bool,
+    ) -> (Index, TaggedVec<Index, Index>)
+    where
+        Index: From<usize>,
+    {
+        let mut front = Vec::with_capacity(self.len());
+        let mut back = Vec::new();
+        let positions: Vec<_> = self
+            .vec
+            .drain(..)
+            .map(|value| {
+                if f(&value) {
+                    front.push(value);
+                    (true, front.len() - 1)
+                } else {
+                    back.push(value);
+                    (false, back.len() - 1)
+                }
+            })
+            .collect();
+
+        let split = front.len();
+        front.append(&mut back);
+        self.vec = front;
+
+        let remap = positions
+            .into_iter()
+            .map(|(is_front, position)| if is_front { position } else { split + position }.into())
+            .collect();
+        (split.into(), remap)
+    }
+
+    /// Moves each value to the new index given by `remap`, dropping values whose new index is `None`.
+    ///
+    /// The remap must map the old indices bijectively onto `0..n` for some `n`, except for removed values.
+    /// Such remaps are returned for example by [`Self::retain_with_remap`], so dependent vectors can be kept in sync with a compacted one.
+    /// Panics if the remap has a different length than `self`, or if it is not bijective.
+    pub fn apply_remap(&mut self, remap: &TaggedVec<Index, Option<Index>>)
+    where
+        Index: Into<usize> + Copy,
+    {
+        // Validate before taking the values, so that a panic leaves `self` untouched.
+        let new_len = remap_len(self.len(), remap);
+        let vec = std::mem::take(self);
+        *self = vec.remapped(remap, new_len);
+    }
+
+    /// Like [`Self::apply_remap`], but consumes `self` and allows the new indices to be of a different type.
+    pub fn into_remapped<NewIndex>(
+        self,
+        remap: &TaggedVec<Index, Option<NewIndex>>,
+    ) -> TaggedVec<NewIndex, Value>
+    where
+        NewIndex: Into<usize> + Copy,
+    {
+        let new_len = remap_len(self.len(), remap);
+        self.remapped(remap, new_len)
+    }
+
+    /// Moves the values according to a remap that was validated by [`remap_len`].
+    fn remapped<NewIndex>(
+        self,
+        remap: &TaggedVec<Index, Option<NewIndex>>,
+        new_len: usize,
+    ) -> TaggedVec<NewIndex, Value>
+    where
+        NewIndex: Into<usize> + Copy,
+    {
+        let mut result: Vec<Option<Value>> = Vec::with_capacity(new_len);
+        result.resize_with(new_len, || None);
+        for (value, new_index) in self.vec.into_iter().zip(&remap.vec) {
+            if let Some(new_index) = new_index {
+                result[(*new_index).into()] = Some(value);
+            }
+        }
+
+        result
+            .into_iter()
+            .map(|value| value.expect("remap was validated"))
+            .collect()
+    }
+}
+
+/// Returns the length of the vector after applying `remap` to a vector of length `len`.
+///
+/// Panics if the remap has a different length than the vector, or if it is not bijective.
+fn remap_len<Index, NewIndex: Into<usize> + Copy>(
+    len: usize,
+    remap: &TaggedVec<Index, Option<NewIndex>>,
+) -> usize {
+    assert_eq!(
+        len,
+        remap.len(),
+        "remap has a different length than the vector"
+    );
+
+    let new_len = remap
+        .vec
+        .iter()
+        .flatten()
+        .map(|new_index| (*new_index).into() + 1)
+        .max()
+        .unwrap_or(0);
+    let mut used = vec![false; new_len];
+    for new_index in remap.vec.iter().flatten() {
+        let new_index = (*new_index).into();
+        assert!(
+            !std::mem::replace(&mut used[new_index], true),
+            "remap maps multiple values to index {new_index}"
+        );
+    }
+    if let Some(new_index) = used.iter().position(|used| !used) {
+        panic!("remap maps no value to index {new_index}");
+    }
+    new_len
+}
+
+impl<Index> TaggedBitSet<Index> {
+    /// Replaces each index in the set with its new index given by `remap`, dropping indices whose new index is `None`.
+    ///
+    /// Panics if the set contains an index that is out of bounds of the remap.
+    pub fn apply_remap(&mut self, remap: &TaggedVec<Index, Option<Index>>)
+    where
+        Index: Into<usize> + Copy,
+    {
+        let mut result = Self::with_capacity(remap.len());
+        for index in self.iter_from::<usize>(0) {
+            let new_index = remap
+                .vec
+                .get(index)
+                .unwrap_or_else(|| panic!("index {index} is out of bounds of the remap"));
+            if let Some(new_index) = new_index {
+                result.insert(*new_index);
+            }
+        }
+        *self = result;
+    }
+}
+
+impl<Index, Value> TaggedVec<Index, Option<Value>> {
+    /// Removes the value at the given index without shifting any other values, leaving a tombstone (`None`) in its slot.
+    ///
+    /// All indices stay valid until the tombstones are squeezed out with [`Self::compact`].
+    /// Returns the removed value, or `None` if the slot was already vacant.
+    pub fn remove_keep_index(&mut self, index: Index) -> Option<Value>
+    where
+        Index: Into<usize>,
+    {
+        self.vec[index.into()].take()
+    }
+
+    /// Removes all tombstones left by [`Self::remove_keep_index`], shifting the remaining values to the left.
+    ///
+    /// Returns a remap from each old index to the new index of its value, or `None` if the slot was vacant.
+    pub fn compact(&mut self) -> TaggedVec<Index, Option<Index>>
+    where
+        Index: From<usize>,
+    {
+        self.retain_with_remap(Option::is_some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{self, AssertUnwindSafe};
+
+    use super::MovedFrom;
+    use crate::{TaggedBitSet, TaggedVec};
+
+    #[test]
+    fn retain_with_remap() {
+        let mut vec = TaggedVec::<usize, _>::from(vec![1, 2, 3, 4, 5]);
+        let remap = vec.retain_with_remap(|value| value % 2 == 1);
+        assert_eq!(vec, [1, 3, 5]);
+        assert_eq!(remap, [Some(0), None, Some(1), None, Some(2)]);
+    }
+
+    #[test]
+    fn retain_and_remove_indices() {
+        let selected: TaggedBitSet<usize> = [0, 2, 10].into_iter().collect();
+
+        let mut vec = TaggedVec::<usize, _>::from(vec!['a', 'b', 'c', 'd']);
+        let remap = vec.retain_indices(&selected);
+        assert_eq!(vec, ['a', 'c']);
+        assert_eq!(remap, [Some(0), None, Some(1), None]);
+
+        let mut vec = TaggedVec::<usize, _>::from(vec!['a', 'b', 'c', 'd']);
+        let remap = vec.remove_indices(&selected);
+        assert_eq!(vec, ['b', 'd']);
+        assert_eq!(remap, [None, Some(0), None, Some(1)]);
+    }
+
+    #[test]
+    fn swap_remove_with_remap() {
+        let mut vec = TaggedVec::<usize, _>::from(vec!['a', 'b', 'c']);
+        assert_eq!(
+            vec.swap_remove_with_remap(0),
+            ('a', Some(MovedFrom { from: 2, to: 0 }))
+        );
+        assert_eq!(vec.swap_remove_with_remap(1), ('b', None));
+        assert_eq!(vec, ['c']);
+    }
+
+    #[test]
+    fn stable_partition_with_remap() {
+        let mut vec = TaggedVec::<usize, _>::from(vec![1, 2, 3, 4, 5]);
+        let (split, remap) = vec.stable_partition_with_remap(|value| value % 2 == 0);
+        assert_eq!(vec, [2, 4, 1, 3, 5]);
+        assert_eq!(split, 2);
+        assert_eq!(remap, [2, 0, 3, 1, 4]);
+    }
+
+    #[test]
+    fn tombstones() {
+        let mut vec = TaggedVec::<usize, _>::from(vec![Some('a'), Some('b'), Some('c')]);
+        assert_eq!(vec.remove_keep_index(1), Some('b'));
+        assert_eq!(vec.remove_keep_index(1), None);
+        assert_eq!(vec[2], Some('c'));
+
+        let remap = vec.compact();
+        assert_eq!(vec, [Some('a'), Some('c')]);
+        assert_eq!(remap, [Some(0), None, Some(1)]);
+    }
+
+    #[test]
+    fn apply_remap() {
+        let mut vec = TaggedVec::<usize, _>::from(vec![1, 2, 3, 4, 5]);
+        let mut names = TaggedVec::<usize, _>::from(vec!["one", "two", "three", "four", "five"]);
+        let mut selected: TaggedBitSet<usize> = [1, 2, 4].into_iter().collect();
+
+        let remap = vec.retain_with_remap(|value| value % 2 == 1);
+        names.apply_remap(&remap);
+        selected.apply_remap(&remap);
+        assert_eq!(names, ["one", "three", "five"]);
+        assert_eq!(selected, [1, 2].into_iter().collect());
+
+        let reversed: TaggedVec<u8, _> =
+            names.into_remapped(&vec![Some(2u8), Some(1), Some(0)].into());
+        assert_eq!(reversed, ["five", "three", "one"]);
+    }
+
+    #[test]
+    fn apply_invalid_remap() {
+        let mut vec = TaggedVec::<usize, _>::from(vec!['a', 'b', 'c']);
+        for remap in [
+            vec![Some(0), Some(1)],
+            vec![Some(0), Some(0), None],
+            vec![Some(0), None, Some(2)],
+        ] {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| vec.apply_remap(&remap.into())));
+            assert!(result.is_err());
+            assert_eq!(vec, ['a', 'b', 'c']);
+        }
+    }
+}
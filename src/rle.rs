@@ -0,0 +1,173 @@
+//! A run-length encoded `TaggedVec`.
+
+use std::marker::PhantomData;
+
+use crate::{TaggedRange, TaggedVec};
+
+/// A vector indexed by `Index` that stores runs of equal consecutive values only once.
+///
+/// Random access takes `O(log r)` time, where `r` is the number of runs.
+/// This saves a lot of memory for vectors that consist of few long runs.
+pub struct RleTaggedVec<Index, Value> {
+    index_type: PhantomData<Index>,
+    /// The runs as pairs of the exclusive end of the run and the value of the run.
+    runs: Vec<(usize, Value)>,
+}
+
+impl<Index, Value> RleTaggedVec<Index, Value> {
+    /// Creates a new empty `RleTaggedVec`.
+    pub fn new() -> Self {
+        Self {
+            index_type: PhantomData,
+            runs: Vec::new(),
+        }
+    }
+
+    /// Returns the number of values in the vector.
+    pub fn len(&self) -> usize {
+        self.runs.last().map_or(0, |(end, _)| *end)
+    }
+
+    /// Returns `true` if the vector contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.runs.is_empty()
+    }
+
+    /// Returns the number of runs in the vector.
+    pub fn run_count(&self) -> usize {
+        self.runs.len()
+    }
+
+    /// Inserts the given value at the back of the vector, returning its index.
+    pub fn push(&mut self, value: Value) -> Index
+    where
+        Index: From<usize>,
+        Value: PartialEq,
+    {
+        self.push_run(value, 1);
+        (self.len() - 1).into()
+    }
+
+    /// Inserts `count` copies of the given value at the back of the vector.
+    pub fn push_run(&mut self, value: Value, count: usize)
+    where
+        Value: PartialEq,
+    {
+        if count == 0 {
+            return;
+        }
+
+        let end = self.len() + count;
+        match self.runs.last_mut() {
+            Some((last_end, last_value)) if *last_value == value => *last_end = end,
+            _ => self.runs.push((end, value)),
+        }
+    }
+
+    /// Returns a reference to the value at the given index, or `None` if the index is out of bounds.
+    pub fn get(&self, index: Index) -> Option<&Value>
+    where
+        Index: Into<usize>,
+    {
+        let index = index.into();
+        let run = self.runs.partition_point(|(end, _)| *end <= index);
+        self.runs.get(run).map(|(_, value)| value)
+    }
+
+    /// Returns an iterator over the runs as pairs of their range of indices and their value.
+    pub fn iter_runs(&self) -> impl DoubleEndedIterator<Item = (TaggedRange<Index>, &Value)>
+    where
+        Index: From<usize>,
+    {
+        self.runs.iter().enumerate().map(|(run, (end, value))| {
+            let start = run
+                .checked_sub(1)
+                .map_or(0, |previous| self.runs[previous].0);
+            (TaggedRange::new(start.into(), (*end).into()), value)
+        })
+    }
+
+    /// Returns an iterator over references to the entries of the vector in order of their indices.
+    pub fn iter(&self) -> impl Iterator<Item = (Index, &Value)>
+    where
+        Index: From<usize>,
+    {
+        let mut start = 0;
+        self.runs.iter().flat_map(move |(end, value)| {
+            let run = (start..*end).map(move |index| (index.into(), value));
+            start = *end;
+            run
+        })
+    }
+
+    /// Decodes the vector into a `TaggedVec`.
+    pub fn to_tagged_vec(&self) -> TaggedVec<Index, Value>
+    where
+        Value: Clone,
+    {
+        let mut result = Vec::with_capacity(self.len());
+        for (end, value) in &self.runs {
+            result.resize(*end, value.clone());
+        }
+        result.into()
+    }
+}
+
+impl<Index, Value> Default for RleTaggedVec<Index, Value> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Index, Value: PartialEq> FromIterator<Value> for RleTaggedVec<Index, Value> {
+    fn from_iter<T: IntoIterator<Item = Value>>(iter: T) -> Self {
+        let mut result = Self::new();
+        for value in iter {
+            result.push_run(value, 1);
+        }
+        result
+    }
+}
+
+impl<Index, Value: PartialEq> From<TaggedVec<Index, Value>> for RleTaggedVec<Index, Value> {
+    fn from(value: TaggedVec<Index, Value>) -> Self {
+        value.vec.into_iter().collect()
+    }
+}
+
+impl<Index: Into<usize>, Value> std::ops::Index<Index> for RleTaggedVec<Index, Value> {
+    type Output = Value;
+
+    fn index(&self, index: Index) -> &Self::Output {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RleTaggedVec;
+    use crate::{TaggedRange, TaggedVec};
+
+    #[test]
+    fn run_length_encoding() {
+        let mut vec = RleTaggedVec::<usize, _>::from(TaggedVec::from(vec!['a', 'a', 'b']));
+        vec.push_run('b', 1000);
+        assert_eq!(vec.push('c'), 1003);
+        assert_eq!((vec.len(), vec.run_count()), (1004, 3));
+
+        assert_eq!(
+            (vec[0], vec[1], vec[2], vec[1002], vec[1003]),
+            ('a', 'a', 'b', 'b', 'c')
+        );
+        assert_eq!(vec.get(1004), None);
+        assert_eq!(
+            vec.iter_runs().collect::<Vec<_>>(),
+            [
+                (TaggedRange::new(0, 2), &'a'),
+                (TaggedRange::new(2, 1003), &'b'),
+                (TaggedRange::new(1003, 1004), &'c')
+            ]
+        );
+        assert!(vec.iter().eq(vec.to_tagged_vec().iter(..)));
+    }
+}
@@ -0,0 +1,106 @@
+//! Detection and collapsing of runs of consecutive values in a `TaggedVec`.
+
+use std::iter;
+
+use crate::{TaggedRange, TaggedVec};
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Returns an iterator over the maximal runs of consecutive values that map to equal keys under `f`.
+    ///
+    /// Each run is reported with its key and its range of indices.
+    /// The ranges are contiguous and cover the whole `TaggedVec`.
+    pub fn runs_by_key<K: PartialEq>(
+        &self,
+        mut f: impl FnMut(&Value) -> K,
+    ) -> impl Iterator<Item = (K, TaggedRange<Index>)>
+    where
+        Index: From<usize>,
+    {
+        let mut values = self.vec.iter().enumerate().peekable();
+
+        iter::from_fn(move || {
+            let (start, value) = values.next()?;
+            let key = f(value);
+            let mut end = start + 1;
+            while let Some((index, value)) = values.peek() {
+                if f(value) != key {
+                    break;
+                }
+                end = index + 1;
+                values.next();
+            }
+
+            Some((key, TaggedRange::new(start.into(), end.into())))
+        })
+    }
+
+    /// Consumes the vector and collapses each maximal run of equal consecutive values into a single value with the length of the run.
+    ///
+    /// Additionally returns the range of original indices covered by each run.
+    #[allow(clippy::type_complexity)]
+    pub fn dedup_consecutive_counts<NewIndex>(
+        self,
+    ) -> (
+        TaggedVec<NewIndex, (Value, usize)>,
+        TaggedVec<NewIndex, TaggedRange<Index>>,
+    )
+    where
+        Index: From<usize>,
+        Value: PartialEq,
+    {
+        let mut counts: Vec<(Value, usize)> = Vec::new();
+        let mut ranges = Vec::new();
+        let mut start = 0;
+
+        for value in self.vec {
+            match counts.last_mut() {
+                Some((last, count)) if *last == value => *count += 1,
+                _ => {
+                    if let Some((_, count)) = counts.last() {
+                        ranges.push(TaggedRange::new(start.into(), (start + count).into()));
+                        start += count;
+                    }
+                    counts.push((value, 1));
+                }
+            }
+        }
+        if let Some((_, count)) = counts.last() {
+            ranges.push(TaggedRange::new(start.into(), (start + count).into()));
+        }
+
+        (counts.into(), ranges.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TaggedRange, TaggedVec};
+
+    #[test]
+    fn runs_by_key() {
+        let vec = TaggedVec::<usize, _>::from(vec![1, 3, 2, 4, 6, 5]);
+        assert_eq!(
+            vec.runs_by_key(|value| value % 2).collect::<Vec<_>>(),
+            [
+                (1, TaggedRange::new(0, 2)),
+                (0, TaggedRange::new(2, 5)),
+                (1, TaggedRange::new(5, 6)),
+            ]
+        );
+    }
+
+    #[test]
+    fn dedup_consecutive_counts() {
+        let vec = TaggedVec::<usize, _>::from(vec!['a', 'a', 'b', 'a', 'a', 'a']);
+        let (counts, ranges) = vec.dedup_consecutive_counts::<u8>();
+        assert_eq!(counts, [('a', 2), ('b', 1), ('a', 3)]);
+        assert_eq!(
+            ranges,
+            [
+                TaggedRange::new(0, 2),
+                TaggedRange::new(2, 3),
+                TaggedRange::new(3, 6),
+            ]
+        );
+    }
+}
@@ -0,0 +1,73 @@
+//! Prefix scans over a `TaggedVec`.
+
+use std::ops::Add;
+
+use crate::TaggedVec;
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Returns a new `TaggedVec` of running accumulations.
+    ///
+    /// The value at each index is `f(previous, value)`, where `previous` is the accumulation at the previous index, or `init` for the first index.
+    pub fn scan<Acc: Clone>(
+        &self,
+        init: Acc,
+        mut f: impl FnMut(&Acc, &Value) -> Acc,
+    ) -> TaggedVec<Index, Acc> {
+        let mut acc = init;
+        self.vec
+            .iter()
+            .map(|value| {
+                acc = f(&acc, value);
+                acc.clone()
+            })
+            .collect()
+    }
+
+    /// Replaces each value with `f(previous, value)`, where `previous` is the already replaced value at the previous index.
+    ///
+    /// The first value is left unchanged.
+    pub fn scan_in_place(&mut self, mut f: impl FnMut(&Value, &Value) -> Value) {
+        for index in 1..self.vec.len() {
+            let (previous, current) = self.vec.split_at_mut(index);
+            current[0] = f(&previous[index - 1], &current[0]);
+        }
+    }
+
+    /// Returns a new `TaggedVec` where the value at each index is the sum of all values up to and including that index.
+    pub fn prefix_sum(&self) -> Self
+    where
+        Value: Clone + Add<Output = Value>,
+    {
+        let mut acc: Option<Value> = None;
+        self.vec
+            .iter()
+            .map(|value| {
+                let sum = match acc.take() {
+                    Some(acc) => acc + value.clone(),
+                    None => value.clone(),
+                };
+                acc = Some(sum.clone());
+                sum
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TaggedVec;
+
+    #[test]
+    fn scan() {
+        let lengths = TaggedVec::<usize, usize>::from(vec![3, 0, 2, 4]);
+        assert_eq!(
+            lengths.scan(10, |acc, length| acc + length),
+            [13, 13, 15, 19]
+        );
+        assert_eq!(lengths.prefix_sum(), [3, 3, 5, 9]);
+
+        let mut lengths = lengths;
+        lengths.scan_in_place(|previous, length| previous.max(length) + 1);
+        assert_eq!(lengths, [3, 4, 5, 6]);
+    }
+}
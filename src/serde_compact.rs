@@ -0,0 +1,145 @@
+//! [`serde`] helpers for `TaggedVec`s of plain-old-data, to be used with `#[serde(with = "tagged_vec::serde_compact")]`.
+//!
+//! Human-readable formats such as JSON get the usual sequence of values.
+//! All other formats get a single byte blob in the portable binary format of [`TaggedVec::write_binary_portable`], which is much faster to process for large numeric vectors.
+
+use std::{fmt, marker::PhantomData, mem};
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, SeqAccess, Visitor},
+    ser,
+};
+
+use crate::{
+    SwapBytes, TaggedVec,
+    portable_io::{HEADER_SIZE, LEN_OFFSET},
+};
+
+/// Reads a byte blob in the portable binary format.
+struct PortableVisitor<Index, Value> {
+    marker: PhantomData<fn() -> TaggedVec<Index, Value>>,
+}
+
+/// Serializes `vec` as a sequence if the format is human-readable, and as a byte blob in the portable binary format otherwise.
+pub fn serialize<Index, Value: Serialize + SwapBytes, S: Serializer>(
+    vec: &TaggedVec<Index, Value>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        vec.serialize(serializer)
+    } else {
+        let mut bytes = Vec::new();
+        vec.write_binary_portable(&mut bytes)
+            .map_err(ser::Error::custom)?;
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+/// Deserializes a `TaggedVec` written by [`serialize`].
+pub fn deserialize<'de, Index, Value: Deserialize<'de> + SwapBytes, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<TaggedVec<Index, Value>, D::Error> {
+    if deserializer.is_human_readable() {
+        TaggedVec::deserialize(deserializer)
+    } else {
+        deserializer.deserialize_bytes(PortableVisitor {
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<'de, Index, Value: SwapBytes> Visitor<'de> for PortableVisitor<Index, Value> {
+    type Value = TaggedVec<Index, Value>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a tagged vector in the portable binary format")
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        // Check the length in the header against the actual data before it is used for allocations.
+        let len = bytes
+            .get(LEN_OFFSET..HEADER_SIZE)
+            .map(|len| u64::from_le_bytes(len.try_into().unwrap()));
+        if len.and_then(|len| len.checked_mul(mem::size_of::<Value>() as u64))
+            != Some((bytes.len() - HEADER_SIZE.min(bytes.len())) as u64)
+        {
+            return Err(E::invalid_length(bytes.len(), &self));
+        }
+
+        TaggedVec::read_binary_portable(bytes).map_err(E::custom)
+    }
+
+    /// Some formats encode byte blobs as a sequence of bytes.
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::new();
+        while let Some(byte) = seq.next_element::<u8>()? {
+            bytes.push(byte);
+        }
+        self.visit_bytes(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::marker::PhantomData;
+
+    use serde::de::Visitor;
+
+    use crate::TaggedVec;
+
+    use super::{PortableVisitor, deserialize, serialize};
+
+    #[test]
+    fn human_readable() {
+        let vec = TaggedVec::<usize, u32>::from(vec![1, 2, 3]);
+        let mut json = Vec::new();
+        serialize(&vec, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        assert_eq!(json, b"[1,2,3]");
+
+        let read: TaggedVec<usize, u32> =
+            deserialize(&mut serde_json::Deserializer::from_slice(&json)).unwrap();
+        assert_eq!(read, [1, 2, 3]);
+    }
+
+    #[test]
+    fn compact() {
+        let vec = TaggedVec::<usize, u32>::from(vec![1, 2, 3]);
+        let mut bytes = Vec::new();
+        vec.write_binary_portable(&mut bytes).unwrap();
+        let visitor = PortableVisitor::<usize, u32> {
+            marker: PhantomData,
+        };
+        let read = visitor
+            .visit_bytes::<serde::de::value::Error>(&bytes)
+            .unwrap();
+        assert_eq!(read, [1, 2, 3]);
+
+        let visitor = PortableVisitor::<usize, u32> {
+            marker: PhantomData,
+        };
+        assert!(
+            visitor
+                .visit_bytes::<serde::de::value::Error>(&bytes[..10])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn compact_forged_length() {
+        let mut bytes = Vec::new();
+        TaggedVec::<usize, u32>::from(vec![1])
+            .write_binary_portable(&mut bytes)
+            .unwrap();
+        for len in [2, 1 << 40, u64::MAX] {
+            bytes[16..24].copy_from_slice(&len.to_le_bytes());
+            let visitor = PortableVisitor::<usize, u32> {
+                marker: PhantomData,
+            };
+            let error = visitor
+                .visit_bytes::<serde::de::value::Error>(&bytes)
+                .unwrap_err();
+            assert!(error.to_string().starts_with("invalid length 28"));
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! [`serde`] support for `TaggedVec`, serializing it as a plain sequence of values.
+//!
+//! The index type is only a marker, so it never needs to be serializable.
+
+use std::fmt;
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{DeserializeSeed, SeqAccess, Visitor},
+};
+
+use crate::{TaggedRange, TaggedVec};
+
+/// The maximum number of bytes reserved up front based on the size hint of a sequence, to avoid huge allocations from untrusted input.
+const MAX_PREALLOCATION_BYTES: usize = 1 << 20;
+
+/// A [`DeserializeSeed`] that appends a sequence of values to an existing `TaggedVec`, created by [`TaggedVec::append_seed`].
+///
+/// Deserializing with it returns the range of the indices of the appended values.
+pub struct TaggedVecAppendSeed<'a, Index, Value> {
+    vec: &'a mut TaggedVec<Index, Value>,
+}
+
+/// Appends the values of a sequence to a `TaggedVec`.
+struct AppendVisitor<'a, Index, Value> {
+    vec: &'a mut TaggedVec<Index, Value>,
+}
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Returns a [`DeserializeSeed`] that appends a deserialized sequence of values to this vector.
+    ///
+    /// This allows to ingest many small batches into one vector without allocating a new vector for each batch.
+    pub fn append_seed(&mut self) -> TaggedVecAppendSeed<'_, Index, Value> {
+        TaggedVecAppendSeed { vec: self }
+    }
+}
+
+impl<Index, Value: Serialize> Serialize for TaggedVec<Index, Value> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.vec.serialize(serializer)
+    }
+}
+
+impl<'de, Index, Value: Deserialize<'de>> Deserialize<'de> for TaggedVec<Index, Value> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut vec = Self::new();
+        deserializer.deserialize_seq(AppendVisitor { vec: &mut vec })?;
+        Ok(vec)
+    }
+}
+
+impl<'de, Index: From<usize>, Value: Deserialize<'de>> DeserializeSeed<'de>
+    for TaggedVecAppendSeed<'_, Index, Value>
+{
+    type Value = TaggedRange<Index>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let start = self.vec.len();
+        deserializer.deserialize_seq(AppendVisitor {
+            vec: &mut *self.vec,
+        })?;
+        Ok(TaggedRange::new(start.into(), self.vec.len().into()))
+    }
+}
+
+impl<'de, Index, Value: Deserialize<'de>> Visitor<'de> for AppendVisitor<'_, Index, Value> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let max_preallocation = MAX_PREALLOCATION_BYTES / std::mem::size_of::<Value>().max(1);
+        self.vec
+            .vec
+            .reserve(seq.size_hint().unwrap_or(0).min(max_preallocation));
+
+        while let Some(value) = seq.next_element()? {
+            self.vec.vec.push(value);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::de::DeserializeSeed;
+
+    use crate::{TaggedRange, TaggedVec};
+
+    /// An index type that implements no serde traits.
+    #[derive(Debug, PartialEq)]
+    struct Index(usize);
+
+    impl From<usize> for Index {
+        fn from(value: usize) -> Self {
+            Self(value)
+        }
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let vec = TaggedVec::<Index, _>::from(vec![1, 2, 3]);
+        let json = serde_json::to_string(&vec).unwrap();
+        assert_eq!(json, "[1,2,3]");
+        assert_eq!(
+            serde_json::from_str::<TaggedVec<Index, i32>>(&json).unwrap(),
+            vec
+        );
+    }
+
+    #[test]
+    fn append_seed() {
+        let mut vec = TaggedVec::<Index, i32>::from(vec![1]);
+        let range = vec
+            .append_seed()
+            .deserialize(&mut serde_json::Deserializer::from_str("[2, 3]"))
+            .unwrap();
+        assert_eq!(range, TaggedRange::new(Index(1), Index(3)));
+        assert_eq!(vec, [1, 2, 3]);
+    }
+}
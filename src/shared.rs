@@ -0,0 +1,118 @@
+//! A `TaggedVec` that can be read concurrently while being updated.
+
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+
+use crate::TaggedVec;
+
+/// A [`TaggedVec`] for read-mostly data shared between threads, in the style of read-copy-update.
+///
+/// Readers obtain cheap immutable snapshots with [`Self::snapshot`], which stay valid and unchanged while they are held.
+/// Writers apply batches of updates to a copy of the current version with [`Self::update`], which is then published atomically.
+/// Concurrent writers are serialised, so no updates are lost.
+/// If an update panics, the current version stays unchanged and the `SharedTaggedVec` remains usable.
+pub struct SharedTaggedVec<Index, Value> {
+    current: RwLock<Arc<TaggedVec<Index, Value>>>,
+    writer: Mutex<()>,
+}
+
+impl<Index, Value> SharedTaggedVec<Index, Value> {
+    /// Creates a new `SharedTaggedVec` with the given initial version.
+    pub fn new(vec: TaggedVec<Index, Value>) -> Self {
+        Self {
+            current: RwLock::new(Arc::new(vec)),
+            writer: Mutex::new(()),
+        }
+    }
+
+    /// Returns the current version.
+    ///
+    /// This only clones an [`Arc`] under a short read lock.
+    /// It does not wait for writers while they apply updates, only while they publish a new version.
+    pub fn snapshot(&self) -> Arc<TaggedVec<Index, Value>> {
+        self.current
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Applies `f` to a copy of the current version and publishes the result as the new version.
+    ///
+    /// Readers observe either the old or the new version, never an intermediate state.
+    pub fn update<R>(&self, f: impl FnOnce(&mut TaggedVec<Index, Value>) -> R) -> R
+    where
+        Value: Clone,
+    {
+        let _writer = self.lock_writer();
+        let mut next = TaggedVec::clone(&self.snapshot());
+        let result = f(&mut next);
+        self.store(next);
+        result
+    }
+
+    /// Replaces the current version with the given vector.
+    ///
+    /// This waits for concurrent updates to finish, so it is never overwritten by an update that started before it.
+    pub fn publish(&self, vec: TaggedVec<Index, Value>) {
+        let _writer = self.lock_writer();
+        self.store(vec);
+    }
+
+    /// Locks the writer mutex.
+    ///
+    /// Poisoning is ignored, since a panicking update only modifies its own copy.
+    fn lock_writer(&self) -> std::sync::MutexGuard<'_, ()> {
+        self.writer.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn store(&self, vec: TaggedVec<Index, Value>) {
+        *self.current.write().unwrap_or_else(PoisonError::into_inner) = Arc::new(vec);
+    }
+}
+
+impl<Index, Value> Default for SharedTaggedVec<Index, Value> {
+    fn default() -> Self {
+        Self::new(TaggedVec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{panic, thread};
+
+    use super::SharedTaggedVec;
+    use crate::TaggedVec;
+
+    #[test]
+    fn snapshot_and_update() {
+        let shared = SharedTaggedVec::<usize, _>::new(TaggedVec::from(vec![1, 2]));
+        let snapshot = shared.snapshot();
+
+        thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    shared.update(|vec| vec.push(3));
+                });
+            }
+        });
+
+        assert_eq!(snapshot.as_untagged_slice(), &[1, 2]);
+        assert_eq!(shared.snapshot().as_untagged_slice(), &[1, 2, 3, 3, 3, 3]);
+    }
+
+    #[test]
+    fn panicking_update() {
+        let shared = SharedTaggedVec::<usize, _>::new(TaggedVec::from(vec![1]));
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            shared.update(|vec| {
+                vec.push(2);
+                panic!("update failed");
+            })
+        }));
+        assert!(result.is_err());
+
+        assert_eq!(shared.snapshot().as_untagged_slice(), &[1]);
+        shared.update(|vec| vec.push(3));
+        shared.publish(TaggedVec::from(vec![4]));
+        assert_eq!(shared.snapshot().as_untagged_slice(), &[4]);
+    }
+}
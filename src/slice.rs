@@ -0,0 +1,272 @@
+//! A borrowed slice of values indexed by a custom type, the tagged counterpart of `[Value]`.
+
+use std::{
+    borrow::Borrow,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Deref, DerefMut, RangeBounds},
+};
+
+use crate::{EntryIterator, EntryIteratorMut, TaggedVec, mapped_range_bounds::MappedRangeBounds};
+
+/// A slice of values indexed by `Index`, like `[Value]` is indexed by `usize`.
+///
+/// This is a dynamically sized type that is always used behind a reference, e.g. `&TaggedSlice` or `&mut TaggedSlice`.
+/// A `TaggedVec` dereferences to a `TaggedSlice`, so functions can accept `&TaggedSlice` to borrow any tagged values without taking ownership.
+/// Like with `[Value]`, the indices of a sub-slice start at zero.
+/// Use [`TaggedVec::view_range`] to keep the original indices instead.
+#[repr(transparent)]
+pub struct TaggedSlice<Index, Value> {
+    index_type: PhantomData<Index>,
+    slice: [Value],
+}
+
+impl<Index, Value> TaggedSlice<Index, Value> {
+    /// Converts an untagged slice into a `TaggedSlice`.
+    pub fn from_untagged(slice: &[Value]) -> &Self {
+        // SAFETY: TaggedSlice is #[repr(transparent)] over [Value] and PhantomData<Index> is a 1-ZST.
+        unsafe { &*(slice as *const [Value] as *const Self) }
+    }
+
+    /// Converts an untagged mutable slice into a mutable `TaggedSlice`.
+    pub fn from_untagged_mut(slice: &mut [Value]) -> &mut Self {
+        // SAFETY: TaggedSlice is #[repr(transparent)] over [Value] and PhantomData<Index> is a 1-ZST.
+        unsafe { &mut *(slice as *mut [Value] as *mut Self) }
+    }
+
+    /// Returns the number of values in the slice.
+    pub fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Returns `true` if the slice contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.slice.is_empty()
+    }
+
+    /// Returns the underlying untagged slice.
+    pub fn as_untagged_slice(&self) -> &[Value] {
+        &self.slice
+    }
+
+    /// Returns the underlying untagged mutable slice.
+    pub fn as_untagged_mut_slice(&mut self) -> &mut [Value] {
+        &mut self.slice
+    }
+
+    /// Returns a reference to the value at the given index, or `None` if the index is out of bounds.
+    pub fn get(&self, index: Index) -> Option<&Value>
+    where
+        Index: Into<usize>,
+    {
+        self.slice.get(index.into())
+    }
+
+    /// Returns a mutable reference to the value at the given index, or `None` if the index is out of bounds.
+    pub fn get_mut(&mut self, index: Index) -> Option<&mut Value>
+    where
+        Index: Into<usize>,
+    {
+        self.slice.get_mut(index.into())
+    }
+
+    /// Returns the sub-slice of the values in the given range.
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn range(&self, range: impl RangeBounds<Index>) -> &Self
+    where
+        Index: Copy,
+        usize: From<Index>,
+    {
+        Self::from_untagged(&self.slice[MappedRangeBounds::new(range).into_bounds()])
+    }
+
+    /// Returns the mutable sub-slice of the values in the given range.
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn range_mut(&mut self, range: impl RangeBounds<Index>) -> &mut Self
+    where
+        Index: Copy,
+        usize: From<Index>,
+    {
+        Self::from_untagged_mut(&mut self.slice[MappedRangeBounds::new(range).into_bounds()])
+    }
+
+    /// Divides the slice into the values before the given index and the values from the given index onwards.
+    ///
+    /// Panics if the index is greater than the length.
+    pub fn split_at(&self, index: Index) -> (&Self, &Self)
+    where
+        Index: Into<usize>,
+    {
+        let (left, right) = self.slice.split_at(index.into());
+        (Self::from_untagged(left), Self::from_untagged(right))
+    }
+
+    /// Like [`Self::split_at`], but returns mutable slices.
+    pub fn split_at_mut(&mut self, index: Index) -> (&mut Self, &mut Self)
+    where
+        Index: Into<usize>,
+    {
+        let (left, right) = self.slice.split_at_mut(index.into());
+        (
+            Self::from_untagged_mut(left),
+            Self::from_untagged_mut(right),
+        )
+    }
+
+    /// Returns an iterator over references to the entries of the slice.
+    pub fn iter(&self) -> EntryIterator<'_, Index, Value>
+    where
+        Index: From<usize>,
+    {
+        EntryIterator::new(&self.slice)
+    }
+
+    /// Returns an iterator over mutable references to the entries of the slice.
+    pub fn iter_mut(&mut self) -> EntryIteratorMut<'_, Index, Value>
+    where
+        Index: From<usize>,
+    {
+        EntryIteratorMut::new(&mut self.slice)
+    }
+
+    /// Returns an iterator over references to the values of the slice.
+    pub fn iter_values(&self) -> std::slice::Iter<'_, Value> {
+        self.slice.iter()
+    }
+
+    /// Returns an iterator over mutable references to the values of the slice.
+    pub fn iter_values_mut(&mut self) -> std::slice::IterMut<'_, Value> {
+        self.slice.iter_mut()
+    }
+
+    /// Copies the values into a new `TaggedVec`.
+    pub fn to_tagged_vec(&self) -> TaggedVec<Index, Value>
+    where
+        Value: Clone,
+    {
+        self.slice.to_vec().into()
+    }
+}
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Returns a `TaggedSlice` containing all values.
+    pub fn as_slice(&self) -> &TaggedSlice<Index, Value> {
+        TaggedSlice::from_untagged(&self.vec)
+    }
+
+    /// Returns a mutable `TaggedSlice` containing all values.
+    pub fn as_mut_slice(&mut self) -> &mut TaggedSlice<Index, Value> {
+        TaggedSlice::from_untagged_mut(&mut self.vec)
+    }
+}
+
+impl<Index, Value> Deref for TaggedVec<Index, Value> {
+    type Target = TaggedSlice<Index, Value>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<Index, Value> DerefMut for TaggedVec<Index, Value> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<Index, Value> Borrow<TaggedSlice<Index, Value>> for TaggedVec<Index, Value> {
+    fn borrow(&self) -> &TaggedSlice<Index, Value> {
+        self.as_slice()
+    }
+}
+
+impl<Index, Value: Clone> ToOwned for TaggedSlice<Index, Value> {
+    type Owned = TaggedVec<Index, Value>;
+
+    fn to_owned(&self) -> Self::Owned {
+        self.to_tagged_vec()
+    }
+}
+
+impl<Index, Value: Debug> Debug for TaggedSlice<Index, Value> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.slice.fmt(f)
+    }
+}
+
+impl<Index, Value: PartialEq> PartialEq for TaggedSlice<Index, Value> {
+    fn eq(&self, other: &Self) -> bool {
+        self.slice == other.slice
+    }
+}
+
+impl<Index, Value: Eq> Eq for TaggedSlice<Index, Value> {}
+
+impl<Index, Value: PartialEq<Other>, Other, const N: usize> PartialEq<[Other; N]>
+    for TaggedSlice<Index, Value>
+{
+    fn eq(&self, other: &[Other; N]) -> bool {
+        self.slice == *other
+    }
+}
+
+impl<'a, Index: From<usize>, Value> IntoIterator for &'a TaggedSlice<Index, Value> {
+    type Item = (Index, &'a Value);
+    type IntoIter = EntryIterator<'a, Index, Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, Index: From<usize>, Value> IntoIterator for &'a mut TaggedSlice<Index, Value> {
+    type Item = (Index, &'a mut Value);
+    type IntoIter = EntryIteratorMut<'a, Index, Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<Index: Into<usize>, Value> std::ops::Index<Index> for TaggedSlice<Index, Value> {
+    type Output = Value;
+
+    fn index(&self, index: Index) -> &Self::Output {
+        &self.slice[index.into()]
+    }
+}
+
+impl<Index: Into<usize>, Value> std::ops::IndexMut<Index> for TaggedSlice<Index, Value> {
+    fn index_mut(&mut self, index: Index) -> &mut Self::Output {
+        &mut self.slice[index.into()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TaggedSlice;
+    use crate::TaggedVec;
+
+    fn sum(slice: &TaggedSlice<usize, i32>) -> i32 {
+        slice.iter_values().sum()
+    }
+
+    #[test]
+    fn tagged_slice() {
+        let mut vec = TaggedVec::<usize, _>::from(vec![1, 2, 3, 4]);
+        assert_eq!(sum(&vec), 10);
+
+        let (left, right) = vec.split_at_mut(1);
+        left[0] = 10;
+        right.range_mut(1..)[0] = 30;
+        assert_eq!(vec, [10, 2, 30, 4]);
+
+        let slice = vec.range(1..3);
+        assert_eq!(*slice, [2, 30]);
+        assert_eq!(slice.get(1), Some(&30));
+        assert_eq!(slice.iter().collect::<Vec<_>>(), [(0, &2), (1, &30)]);
+        assert_eq!(slice.to_owned(), [2, 30]);
+    }
+}
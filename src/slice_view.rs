@@ -0,0 +1,133 @@
+//! Zero-copy views of `TaggedVec`s stored in the binary format.
+
+use std::{
+    io::{Error, ErrorKind},
+    marker::PhantomData,
+    mem,
+};
+
+use crate::{Pod, TaggedVec};
+
+/// A borrowed read-only view of values stored in the format of [`TaggedVec::write_binary`].
+///
+/// The values are reinterpreted in place, without copying or allocating.
+#[derive(Debug)]
+pub struct TaggedSliceView<'a, Index, Value> {
+    index_type: PhantomData<Index>,
+    slice: &'a [Value],
+}
+
+impl<'a, Index, Value: Pod> TaggedSliceView<'a, Index, Value> {
+    /// Creates a view of the given bytes, which must have been written by [`TaggedVec::write_binary`] on a machine with the same pointer size and endianness.
+    ///
+    /// Fails with [`ErrorKind::InvalidData`] if the length stored in the header does not match the number of bytes, or if the values are not properly aligned in memory.
+    pub fn from_bytes(bytes: &'a [u8]) -> std::io::Result<Self> {
+        let header_size = mem::size_of::<usize>();
+        let Some((header, data)) = bytes.split_at_checked(header_size) else {
+            return Err(Error::new(ErrorKind::InvalidData, "missing length header"));
+        };
+        let len = usize::from_ne_bytes(header.try_into().unwrap());
+
+        if len.checked_mul(mem::size_of::<Value>()) != Some(data.len()) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "header specifies {len} values, but found {} bytes of data",
+                    data.len()
+                ),
+            ));
+        }
+        if data.as_ptr().align_offset(mem::align_of::<Value>()) != 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "data is not aligned for the value type",
+            ));
+        }
+
+        // SAFETY: the data has the right length and alignment, and `Value: Pod` allows any bit pattern.
+        let slice = unsafe { std::slice::from_raw_parts(data.as_ptr() as *const Value, len) };
+        Ok(Self {
+            index_type: PhantomData,
+            slice,
+        })
+    }
+}
+
+impl<'a, Index, Value> TaggedSliceView<'a, Index, Value> {
+    /// Returns the number of values in the view.
+    pub fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Returns `true` if the view contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.slice.is_empty()
+    }
+
+    /// Returns a reference to the value at the given index, or `None` if the index is out of bounds.
+    pub fn get(&self, index: Index) -> Option<&'a Value>
+    where
+        Index: Into<usize>,
+    {
+        self.slice.get(index.into())
+    }
+
+    /// Returns an iterator over the entries of the view.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (Index, &'a Value)> + ExactSizeIterator
+    where
+        Index: From<usize>,
+    {
+        self.slice
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (index.into(), value))
+    }
+
+    /// Returns the untagged slice of values.
+    pub fn as_untagged_slice(&self) -> &'a [Value] {
+        self.slice
+    }
+
+    /// Copies the values into a new `TaggedVec`.
+    pub fn to_tagged_vec(&self) -> TaggedVec<Index, Value>
+    where
+        Value: Clone,
+    {
+        self.slice.to_vec().into()
+    }
+}
+
+impl<Index: Into<usize>, Value> std::ops::Index<Index> for TaggedSliceView<'_, Index, Value> {
+    type Output = Value;
+
+    fn index(&self, index: Index) -> &Self::Output {
+        &self.slice[index.into()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TaggedSliceView;
+    use crate::TaggedVec;
+
+    #[test]
+    fn from_bytes() {
+        let vec = TaggedVec::<usize, u64>::from(vec![42, 1337]);
+        let mut bytes = Vec::new();
+        vec.write_binary(&mut bytes).unwrap();
+
+        // Copy into a buffer with the alignment of `u64`.
+        let mut aligned = vec![0u64; bytes.len() / 8];
+        let aligned: &mut [u8] =
+            unsafe { std::slice::from_raw_parts_mut(aligned.as_mut_ptr() as *mut u8, bytes.len()) };
+        aligned.copy_from_slice(&bytes);
+
+        let view = TaggedSliceView::<usize, u64>::from_bytes(aligned).unwrap();
+        assert_eq!(view.len(), 2);
+        assert_eq!(view[1], 1337);
+        assert_eq!(view.to_tagged_vec(), vec);
+
+        assert!(TaggedSliceView::<usize, u64>::from_bytes(&aligned[..12]).is_err());
+        assert!(TaggedSliceView::<usize, u32>::from_bytes(aligned).is_err());
+    }
+}
@@ -0,0 +1,124 @@
+//! A window over the most recent values of a stream, addressed by their global indices.
+
+use std::{collections::VecDeque, marker::PhantomData};
+
+use crate::TaggedRange;
+
+/// Keeps the last `capacity` pushed values, while still addressing them by the index they were pushed at.
+///
+/// Indices are assigned consecutively from zero like in a `TaggedVec`, and stay valid for as long as the value is in the window.
+pub struct SlidingWindow<Index, Value> {
+    index_type: PhantomData<Index>,
+    values: VecDeque<Value>,
+    capacity: usize,
+    /// The global index of the front value of the window.
+    offset: usize,
+}
+
+impl<Index, Value> SlidingWindow<Index, Value> {
+    /// Creates a new empty `SlidingWindow` that keeps at most `capacity` values.
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "sliding window capacity must be positive");
+        Self {
+            index_type: PhantomData,
+            values: VecDeque::with_capacity(capacity),
+            capacity,
+            offset: 0,
+        }
+    }
+
+    /// Returns the maximum number of values kept in the window.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of values currently in the window.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the window contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the range of indices of the values currently in the window.
+    pub fn index_range(&self) -> TaggedRange<Index>
+    where
+        Index: From<usize>,
+    {
+        TaggedRange::new(self.offset.into(), (self.offset + self.values.len()).into())
+    }
+
+    /// Inserts the given value at the back of the window, returning its index.
+    ///
+    /// If the window is full, then its front value is dropped and returned with its index.
+    pub fn push(&mut self, value: Value) -> (Index, Option<(Index, Value)>)
+    where
+        Index: From<usize>,
+    {
+        let evicted = if self.values.len() == self.capacity {
+            let evicted = self
+                .values
+                .pop_front()
+                .map(|value| (self.offset.into(), value));
+            self.offset += 1;
+            evicted
+        } else {
+            None
+        };
+
+        self.values.push_back(value);
+        ((self.offset + self.values.len() - 1).into(), evicted)
+    }
+
+    /// Returns a reference to the value at the given index, or `None` if it is not in the window.
+    pub fn get(&self, index: Index) -> Option<&Value>
+    where
+        Index: Into<usize>,
+    {
+        self.values.get(index.into().checked_sub(self.offset)?)
+    }
+
+    /// Returns a mutable reference to the value at the given index, or `None` if it is not in the window.
+    pub fn get_mut(&mut self, index: Index) -> Option<&mut Value>
+    where
+        Index: Into<usize>,
+    {
+        self.values.get_mut(index.into().checked_sub(self.offset)?)
+    }
+
+    /// Returns an iterator over the entries in the window in order of their indices.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (Index, &Value)> + ExactSizeIterator
+    where
+        Index: From<usize>,
+    {
+        let offset = self.offset;
+        self.values
+            .iter()
+            .enumerate()
+            .map(move |(position, value)| ((offset + position).into(), value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SlidingWindow;
+    use crate::TaggedRange;
+
+    #[test]
+    fn sliding_window() {
+        let mut window = SlidingWindow::<usize, _>::new(2);
+        assert_eq!(window.push('a'), (0, None));
+        assert_eq!(window.push('b'), (1, None));
+        assert_eq!(window.push('c'), (2, Some((0, 'a'))));
+
+        assert_eq!(window.get(0), None);
+        assert_eq!(window.get(2), Some(&'c'));
+        *window.get_mut(1).unwrap() = 'B';
+        assert_eq!(window.index_range(), TaggedRange::new(1, 3));
+        assert_eq!(window.iter().collect::<Vec<_>>(), [(1, &'B'), (2, &'c')]);
+    }
+}
@@ -0,0 +1,137 @@
+//! Comparison-based sorting of `TaggedVec`s.
+
+use std::cmp::Ordering;
+
+use crate::TaggedVec;
+
+/// The direction in which a key is sorted by [`TaggedVec::sort_by_keys`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    /// Smaller keys come first.
+    Ascending,
+    /// Larger keys come first.
+    Descending,
+}
+
+/// A tuple of pairs of key extractors and [`SortOrder`]s, which orders values lexicographically by their keys.
+pub trait SortKeys<Value> {
+    /// Compares two values by the first key, using the next key only if the previous keys are equal.
+    fn compare(&self, a: &Value, b: &Value) -> Ordering;
+}
+
+macro_rules! impl_sort_keys {
+    ($(($key:ident, $extractor:ident, $index:tt)),*) => {
+        impl<Value, $($key: Ord, $extractor: Fn(&Value) -> $key),*> SortKeys<Value>
+            for ($(($extractor, SortOrder),)*)
+        {
+            fn compare(&self, a: &Value, b: &Value) -> Ordering {
+                Ordering::Equal
+                    $(.then_with(|| {
+                        let (extractor, order) = &self.$index;
+                        let ordering = extractor(a).cmp(&extractor(b));
+                        match order {
+                            SortOrder::Ascending => ordering,
+                            SortOrder::Descending => ordering.reverse(),
+                        }
+                    }))*
+            }
+        }
+    };
+}
+
+impl_sort_keys!((K0, F0, 0));
+impl_sort_keys!((K0, F0, 0), (K1, F1, 1));
+impl_sort_keys!((K0, F0, 0), (K1, F1, 1), (K2, F2, 2));
+impl_sort_keys!((K0, F0, 0), (K1, F1, 1), (K2, F2, 2), (K3, F3, 3));
+impl_sort_keys!(
+    (K0, F0, 0),
+    (K1, F1, 1),
+    (K2, F2, 2),
+    (K3, F3, 3),
+    (K4, F4, 4)
+);
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Returns the indices of the vector ordered by their values according to `cmp`, without moving any values.
+    ///
+    /// The sort is stable, i.e. indices of equal values are in ascending order.
+    pub fn sorted_indices_by(&self, mut cmp: impl FnMut(&Value, &Value) -> Ordering) -> Vec<Index>
+    where
+        Index: From<usize>,
+    {
+        let mut indices: Vec<usize> = (0..self.vec.len()).collect();
+        indices.sort_by(|a, b| cmp(&self.vec[*a], &self.vec[*b]));
+        indices.into_iter().map(Index::from).collect()
+    }
+
+    /// Returns the indices of the vector ordered by the keys of their values, without moving any values.
+    ///
+    /// The sort is stable, i.e. indices of values with equal keys are in ascending order.
+    pub fn sorted_indices_by_key<Key: Ord>(&self, mut key: impl FnMut(&Value) -> Key) -> Vec<Index>
+    where
+        Index: From<usize>,
+    {
+        self.sorted_indices_by(|a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Sorts the vector by multiple keys, each in ascending or descending order.
+    ///
+    /// The keys are given as a tuple of pairs of a key extractor and a [`SortOrder`], and are compared lexicographically.
+    /// The sort is stable.
+    pub fn sort_by_keys(&mut self, keys: impl SortKeys<Value>) {
+        self.vec.sort_by(|a, b| keys.compare(a, b));
+    }
+
+    /// Like [`Self::sort_by_keys`], but additionally returns a remap from each old index to the new index of its value.
+    pub fn sort_by_keys_with_remap(&mut self, keys: impl SortKeys<Value>) -> TaggedVec<Index, Index>
+    where
+        Index: From<usize>,
+    {
+        let order = self.sorted_positions_by(|a, b| keys.compare(a, b));
+        let mut slots: Vec<_> = self.vec.drain(..).map(Some).collect();
+        let mut remap = vec![0; order.len()];
+        self.vec = order
+            .into_iter()
+            .enumerate()
+            .map(|(new_index, old_index)| {
+                remap[old_index] = new_index;
+                slots[old_index].take().unwrap()
+            })
+            .collect();
+        remap.into_iter().map(Index::from).collect()
+    }
+
+    fn sorted_positions_by(&self, mut cmp: impl FnMut(&Value, &Value) -> Ordering) -> Vec<usize> {
+        let mut positions: Vec<usize> = (0..self.vec.len()).collect();
+        positions.sort_by(|a, b| cmp(&self.vec[*a], &self.vec[*b]));
+        positions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{SortOrder, TaggedVec};
+
+    #[test]
+    fn sorted_indices() {
+        let vec = TaggedVec::<usize, _>::from(vec![30, 10, 20, 10]);
+        assert_eq!(vec.sorted_indices_by(|a, b| b.cmp(a)), [0, 2, 1, 3]);
+        assert_eq!(vec.sorted_indices_by_key(|value| *value), [1, 3, 2, 0]);
+        assert_eq!(vec, [30, 10, 20, 10]);
+    }
+
+    #[test]
+    fn sort_by_keys() {
+        type Row = (char, i32);
+        let mut vec = TaggedVec::<usize, Row>::from(vec![('b', 1), ('a', 2), ('b', 3), ('a', 1)]);
+        vec.sort_by_keys((
+            (|row: &Row| row.0, SortOrder::Ascending),
+            (|row: &Row| row.1, SortOrder::Descending),
+        ));
+        assert_eq!(vec, [('a', 2), ('a', 1), ('b', 3), ('b', 1)]);
+
+        let remap = vec.sort_by_keys_with_remap(((|row: &Row| row.1, SortOrder::Ascending),));
+        assert_eq!(vec, [('a', 1), ('b', 1), ('a', 2), ('b', 3)]);
+        assert_eq!(remap, [2, 0, 3, 1]);
+    }
+}
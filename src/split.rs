@@ -0,0 +1,163 @@
+//! Splitting a `TaggedVec` into subslices separated by values matching a predicate.
+
+use std::iter;
+
+use crate::{TaggedRange, TaggedVec};
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Returns an iterator over the subslices separated by values that match `pred`, like [`slice::split`].
+    ///
+    /// Each subslice is returned together with the index of its first value within the `TaggedVec`.
+    /// The matched values are not contained in the subslices.
+    pub fn split(&self, pred: impl FnMut(&Value) -> bool) -> impl Iterator<Item = (Index, &[Value])>
+    where
+        Index: From<usize>,
+    {
+        self.splitn(usize::MAX, pred)
+    }
+
+    /// Like [`Self::split`], but returns at most `n` subslices, like [`slice::splitn`].
+    ///
+    /// The last subslice contains the remainder of the `TaggedVec`.
+    pub fn splitn(
+        &self,
+        n: usize,
+        mut pred: impl FnMut(&Value) -> bool,
+    ) -> impl Iterator<Item = (Index, &[Value])>
+    where
+        Index: From<usize>,
+    {
+        let slice = self.as_untagged_slice();
+        let mut start = 0;
+        let mut remaining = n;
+
+        iter::from_fn(move || {
+            if remaining == 0 {
+                return None;
+            }
+            remaining -= 1;
+
+            let rest = &slice[start..];
+            let end = if remaining == 0 {
+                None
+            } else {
+                rest.iter().position(&mut pred)
+            };
+
+            let base = start;
+            if let Some(end) = end {
+                start += end + 1;
+                Some((base.into(), &rest[..end]))
+            } else {
+                remaining = 0;
+                Some((base.into(), rest))
+            }
+        })
+    }
+
+    /// Like [`Self::split`], but the matched values are contained at the end of the preceding subslice, like [`slice::split_inclusive`].
+    ///
+    /// If the last value matches, then no empty subslice is returned after it.
+    pub fn split_inclusive(
+        &self,
+        mut pred: impl FnMut(&Value) -> bool,
+    ) -> impl Iterator<Item = (Index, &[Value])>
+    where
+        Index: From<usize>,
+    {
+        let slice = self.as_untagged_slice();
+        let mut start = 0;
+
+        iter::from_fn(move || {
+            let rest = &slice[start..];
+            if rest.is_empty() {
+                return None;
+            }
+
+            let end = rest
+                .iter()
+                .position(&mut pred)
+                .map(|end| end + 1)
+                .unwrap_or(rest.len());
+            let base = start;
+            start += end;
+            Some((base.into(), &rest[..end]))
+        })
+    }
+
+    /// Splits the `TaggedVec` into `n` disjoint mutable subslices of roughly equal length, see [`TaggedRange::split_into`].
+    ///
+    /// Each subslice is returned together with the index of its first value within the `TaggedVec`.
+    /// This allows to hand one subslice to each thread, for example under [`std::thread::scope`].
+    /// Panics if `n` is zero.
+    pub fn split_into_mut(&mut self, n: usize) -> Vec<(Index, &mut [Value])>
+    where
+        Index: From<usize>,
+    {
+        let mut rest = self.vec.as_mut_slice();
+        TaggedRange::<usize>::new(0, rest.len())
+            .split_into(n)
+            .into_iter()
+            .map(|range| {
+                let (part, remainder) = std::mem::take(&mut rest).split_at_mut(range.len());
+                rest = remainder;
+                (range.start.into(), part)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TaggedVec;
+
+    #[test]
+    fn split() {
+        let vec = TaggedVec::<usize, _>::from(vec![1, 0, 2, 3, 0, 0, 4]);
+        let is_separator = |value: &i32| *value == 0;
+
+        assert_eq!(
+            vec.split(is_separator).collect::<Vec<_>>(),
+            [(0, &[1][..]), (2, &[2, 3][..]), (5, &[][..]), (6, &[4][..])]
+        );
+        assert_eq!(
+            vec.splitn(2, is_separator).collect::<Vec<_>>(),
+            [(0, &[1][..]), (2, &[2, 3, 0, 0, 4][..])]
+        );
+        assert_eq!(
+            vec.split_inclusive(is_separator).collect::<Vec<_>>(),
+            [
+                (0, &[1, 0][..]),
+                (2, &[2, 3, 0][..]),
+                (5, &[0][..]),
+                (6, &[4][..])
+            ]
+        );
+        assert_eq!(
+            TaggedVec::<usize, i32>::new()
+                .split(is_separator)
+                .collect::<Vec<_>>(),
+            [(0, &[][..])]
+        );
+    }
+
+    #[test]
+    fn split_into_mut() {
+        let mut vec = TaggedVec::<usize, _>::from(vec![0; 5]);
+        let parts = vec.split_into_mut(2);
+        assert_eq!(
+            parts
+                .iter()
+                .map(|(index, part)| (*index, part.len()))
+                .collect::<Vec<_>>(),
+            [(0, 3), (3, 2)]
+        );
+
+        std::thread::scope(|scope| {
+            for (index, part) in parts {
+                scope.spawn(move || part.fill(index));
+            }
+        });
+        assert_eq!(vec, [0, 0, 0, 3, 3]);
+    }
+}
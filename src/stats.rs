@@ -0,0 +1,92 @@
+//! Numeric reducers over `TaggedVec`s based on [`num_traits`].
+
+use num_traits::{ToPrimitive, Zero};
+
+use crate::TaggedVec;
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Returns the sum of all values, or zero if the vector is empty.
+    pub fn sum(&self) -> Value
+    where
+        Value: Zero + Clone,
+    {
+        self.vec
+            .iter()
+            .fold(Value::zero(), |sum, value| sum + value.clone())
+    }
+
+    /// Returns the arithmetic mean of all values as `f64`, or `None` if the vector is empty or a value cannot be represented as `f64`.
+    pub fn mean(&self) -> Option<f64>
+    where
+        Value: ToPrimitive,
+    {
+        if self.vec.is_empty() {
+            return None;
+        }
+
+        let sum = self
+            .vec
+            .iter()
+            .try_fold(0.0, |sum, value| Some(sum + value.to_f64()?))?;
+        Some(sum / self.vec.len() as f64)
+    }
+
+    /// Returns the entries with the minimum and the maximum value, or `None` if the vector is empty.
+    ///
+    /// If multiple entries are minimal or maximal, then the one with the smallest index is returned.
+    /// Values that are incomparable to the current extreme, such as `NaN`, are skipped.
+    /// If no value is comparable to itself, then the first entry is returned as both minimum and maximum.
+    #[allow(clippy::type_complexity)]
+    pub fn minmax(&self) -> Option<((Index, &Value), (Index, &Value))>
+    where
+        Index: From<usize>,
+        Value: PartialOrd,
+    {
+        // Start from the first value that is comparable to itself, so that a leading `NaN` is skipped as well.
+        let start = self
+            .vec
+            .iter()
+            .position(|value| value.partial_cmp(value).is_some())
+            .unwrap_or(0);
+        let first = self.vec.get(start)?;
+        let (mut min, mut max) = ((start, first), (start, first));
+        for (index, value) in self.vec.iter().enumerate().skip(start + 1) {
+            if value < min.1 {
+                min = (index, value);
+            }
+            if value > max.1 {
+                max = (index, value);
+            }
+        }
+        Some(((min.0.into(), min.1), (max.0.into(), max.1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TaggedVec;
+
+    #[test]
+    fn stats() {
+        let vec = TaggedVec::<usize, f64>::from(vec![2.0, -1.0, 5.0, -1.0, 5.0]);
+        assert_eq!(vec.sum(), 10.0);
+        assert_eq!(vec.mean(), Some(2.0));
+        assert_eq!(vec.minmax(), Some(((1, &-1.0), (2, &5.0))));
+
+        let empty = TaggedVec::<usize, u32>::new();
+        assert_eq!(empty.sum(), 0);
+        assert_eq!(empty.mean(), None);
+        assert_eq!(empty.minmax(), None);
+    }
+
+    #[test]
+    fn minmax_nan() {
+        let vec = TaggedVec::<usize, f64>::from(vec![f64::NAN, 1.0, f64::NAN, 2.0]);
+        assert_eq!(vec.minmax(), Some(((1, &1.0), (3, &2.0))));
+
+        let vec = TaggedVec::<usize, f64>::from(vec![f64::NAN]);
+        let ((min_index, min), (max_index, max)) = vec.minmax().unwrap();
+        assert_eq!((min_index, max_index), (0, 0));
+        assert!(min.is_nan() && max.is_nan());
+    }
+}
@@ -0,0 +1,59 @@
+//! Rendering of `TaggedVec`s as aligned text tables.
+
+use crate::TaggedVec;
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Renders the vector as a text table with one row per entry, for debugging output.
+    ///
+    /// The first column contains the position of each entry, and the remaining `N` columns contain the cells returned by `f` for the entry.
+    /// Columns are separated by two spaces, the position column is right-aligned and all other columns are left-aligned.
+    pub fn format_table<const N: usize>(&self, f: impl Fn(Index, &Value) -> [String; N]) -> String
+    where
+        Index: From<usize>,
+    {
+        let rows: Vec<_> = self
+            .vec
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (index.to_string(), f(index.into(), value)))
+            .collect();
+
+        let index_width = rows.last().map_or(0, |(index, _)| index.len());
+        let mut widths = [0; N];
+        for (_, cells) in &rows {
+            for (width, cell) in widths.iter_mut().zip(cells) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let mut table = String::new();
+        for (index, cells) in rows {
+            let mut line = format!("{index:>index_width$}");
+            for (cell, width) in cells.iter().zip(widths) {
+                line.push_str(&format!("  {cell:<width$}"));
+            }
+            table.push_str(line.trim_end());
+            table.push('\n');
+        }
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TaggedVec;
+
+    #[test]
+    fn format_table() {
+        let vec = TaggedVec::<usize, _>::from(
+            (0..11)
+                .map(|value| (value, value * value))
+                .collect::<Vec<_>>(),
+        );
+        let table = vec.format_table(|_, (value, square)| [value.to_string(), square.to_string()]);
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(lines.len(), 11);
+        assert_eq!(lines[0], " 0  0   0");
+        assert_eq!(lines[10], "10  10  100");
+    }
+}
@@ -0,0 +1,133 @@
+//! A copyable half-open range of indices of a `TaggedVec`.
+
+use std::ops::{Bound, Range, RangeBounds};
+
+use crate::IndexIterator;
+
+/// A half-open range `start..end` of indices of a `TaggedVec`.
+///
+/// In contrast to [`Range`], this type is [`Copy`] and its length and iterator are computed via the `usize` representation of the indices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TaggedRange<Index> {
+    /// The first index of the range.
+    pub start: Index,
+    /// The index after the last index of the range.
+    pub end: Index,
+}
+
+impl<Index> TaggedRange<Index> {
+    /// Creates a new range `start..end`.
+    pub fn new(start: Index, end: Index) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the number of indices in the range.
+    pub fn len(&self) -> usize
+    where
+        Index: Into<usize> + Copy,
+    {
+        self.end.into().saturating_sub(self.start.into())
+    }
+
+    /// Returns `true` if the range contains no indices.
+    pub fn is_empty(&self) -> bool
+    where
+        Index: Into<usize> + Copy,
+    {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the range contains the given index.
+    pub fn contains(&self, index: Index) -> bool
+    where
+        Index: Into<usize> + Copy,
+    {
+        (self.start.into()..self.end.into()).contains(&index.into())
+    }
+
+    /// Returns an iterator over the indices in the range.
+    pub fn iter(&self) -> IndexIterator<Index>
+    where
+        Index: Into<usize> + Copy,
+    {
+        let start = self.start.into();
+        IndexIterator::new(start, self.end.into().max(start))
+    }
+
+    /// Splits the range into `n` disjoint consecutive ranges that cover it.
+    ///
+    /// The lengths of the returned ranges differ by at most one, where longer ranges come first.
+    /// Panics if `n` is zero.
+    pub fn split_into(&self, n: usize) -> Vec<Self>
+    where
+        Index: From<usize> + Into<usize> + Copy,
+    {
+        assert!(n > 0, "cannot split a range into zero parts");
+        let start: usize = self.start.into();
+        let (base_len, remainder) = (self.len() / n, self.len() % n);
+
+        let mut offset = start;
+        (0..n)
+            .map(|part| {
+                let part_start = offset;
+                offset += base_len + usize::from(part < remainder);
+                Self::new(part_start.into(), offset.into())
+            })
+            .collect()
+    }
+}
+
+impl<Index> RangeBounds<Index> for TaggedRange<Index> {
+    fn start_bound(&self) -> Bound<&Index> {
+        Bound::Included(&self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&Index> {
+        Bound::Excluded(&self.end)
+    }
+}
+
+impl<Index> IntoIterator for TaggedRange<Index>
+where
+    Index: From<usize> + Into<usize> + Copy,
+{
+    type Item = Index;
+    type IntoIter = IndexIterator<Index>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<Index> From<Range<Index>> for TaggedRange<Index> {
+    fn from(value: Range<Index>) -> Self {
+        Self::new(value.start, value.end)
+    }
+}
+
+impl<Index> From<TaggedRange<Index>> for Range<Index> {
+    fn from(value: TaggedRange<Index>) -> Self {
+        value.start..value.end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TaggedRange;
+
+    #[test]
+    fn split_into() {
+        assert_eq!(
+            TaggedRange::new(2, 9).split_into(3),
+            [
+                TaggedRange::new(2, 5),
+                TaggedRange::new(5, 7),
+                TaggedRange::new(7, 9)
+            ]
+        );
+        assert_eq!(
+            TaggedRange::new(0, 1).split_into(2),
+            [TaggedRange::new(0, 1), TaggedRange::new(1, 1)]
+        );
+    }
+}
@@ -3,21 +3,130 @@ use std::{
     time::{Duration, Instant},
 };
 
-use crate::TaggedVec;
+use crate::{TaggedRange, TaggedVec};
 
 #[test]
 fn delete_multi() {
     let mut v = TaggedVec::<usize, _>::from_iter([0, 1, 2, 3, 4]);
     v.remove_multi([0, 4]);
-    assert_eq!(v, vec![1, 2, 3].into());
+    assert_eq!(v, [1, 2, 3]);
 
     let mut v = TaggedVec::<usize, _>::from_iter([0, 1, 2, 3, 4]);
     v.remove_multi([0, 2, 4]);
-    assert_eq!(v, vec![1, 3].into());
+    assert_eq!(v, [1, 3]);
 
     let mut v = TaggedVec::<usize, _>::from_iter([0, 1, 2, 3, 4]);
     v.remove_multi([1, 3]);
-    assert_eq!(v, vec![0, 2, 4].into());
+    assert_eq!(v, [0, 2, 4]);
+}
+
+#[test]
+fn get_wrapped_and_clamped() {
+    let v = TaggedVec::<usize, _>::from_iter([0, 1, 2]);
+    assert_eq!(v.get_wrapped(4), Some(&1));
+    assert_eq!(v.get_clamped(4), Some(&2));
+    assert_eq!(v.get_clamped(1), Some(&1));
+
+    let v = TaggedVec::<usize, i32>::new();
+    assert_eq!(v.get_wrapped(4), None);
+    assert_eq!(v.get_clamped(0), None);
+}
+
+#[test]
+fn replace_and_take_at() {
+    let mut v = TaggedVec::<usize, _>::from_iter([1, 2, 3]);
+    assert_eq!(v.replace(1, 4), 2);
+    assert_eq!(v.take_at(2), 3);
+    assert_eq!(v, [1, 4, 0]);
+}
+
+#[test]
+fn update() {
+    let mut v = TaggedVec::<usize, _>::from_iter([1, 2, 3]);
+    assert_eq!(v.update(1, |value| std::mem::replace(value, 5)), 2);
+    assert_eq!(v.try_update(2, |value| *value += 1), Some(()));
+    assert_eq!(v.try_update(3, |value| *value += 1), None);
+    assert_eq!(v, [1, 5, 4]);
+}
+
+#[test]
+fn index_chunks() {
+    let v = TaggedVec::<usize, _>::from_iter(0..5);
+    assert_eq!(
+        v.index_chunks(2).collect::<Vec<_>>(),
+        [
+            TaggedRange::new(0, 2),
+            TaggedRange::new(2, 4),
+            TaggedRange::new(4, 5)
+        ]
+    );
+}
+
+#[test]
+fn from_iter_entries() {
+    let v = TaggedVec::<usize, char>::from_iter([(2, 'c'), (0, 'a'), (1, 'b')]);
+    assert_eq!(v, ['a', 'b', 'c']);
+}
+
+#[test]
+#[should_panic = "missing index 1"]
+fn from_iter_entries_missing() {
+    TaggedVec::<usize, char>::from_iter([(2, 'c'), (0, 'a')]);
+}
+
+#[test]
+#[should_panic = "duplicate index 0"]
+fn from_iter_entries_duplicate() {
+    TaggedVec::<usize, char>::from_iter([(1, 'b'), (0, 'a'), (0, 'c')]);
+}
+
+#[test]
+fn extend_entries() {
+    let mut v = TaggedVec::<usize, char>::from_iter(['a']);
+    v.extend([(1, 'b'), (2, 'c')]);
+    assert_eq!(v, ['a', 'b', 'c']);
+}
+
+#[test]
+#[should_panic = "out-of-sequence index"]
+fn extend_entries_out_of_sequence() {
+    let mut v = TaggedVec::<usize, char>::from_iter(['a']);
+    v.extend([(2, 'c')]);
+}
+
+#[test]
+fn from_sparse_iter() {
+    let v = TaggedVec::<usize, _>::from_sparse_iter([(3, 'd'), (1, 'b')], 4);
+    assert_eq!(v, ['\0', 'b', '\0', 'd']);
+
+    let v = TaggedVec::<usize, _>::from_sparse_iter_with([(1, 10)], 3, |index| index);
+    assert_eq!(v, [0, 10, 2]);
+}
+
+#[test]
+fn into_iter_ref() {
+    let mut v = TaggedVec::<usize, _>::from_iter(['a', 'b']);
+    for (index, value) in &mut v {
+        if index == 1 {
+            *value = 'c';
+        }
+    }
+
+    let mut entries = Vec::new();
+    for (index, value) in &v {
+        entries.push((index, *value));
+    }
+    assert_eq!(entries, [(0, 'a'), (1, 'c')]);
+}
+
+#[test]
+fn iter_values_range() {
+    let mut v = TaggedVec::<usize, _>::from_iter([0, 1, 2, 3]);
+    v.iter_values_range_mut(2..).for_each(|value| *value *= 10);
+    assert_eq!(
+        v.iter_values_range(1..=2).copied().collect::<Vec<_>>(),
+        [1, 20]
+    );
 }
 
 #[test]
@@ -64,3 +173,165 @@ fn iter_skip() {
             || skip_time < Duration::from_millis(10) && no_skip_time < Duration::from_millis(10)
     );
 }
+
+#[test]
+fn const_new() {
+    static EMPTY: TaggedVec<usize, u32> = TaggedVec::new();
+    const ALSO_EMPTY: TaggedVec<usize, u32> = TaggedVec::EMPTY;
+    assert!(EMPTY.is_empty());
+    assert_eq!(ALSO_EMPTY, EMPTY);
+}
+
+#[test]
+fn eq_untagged() {
+    let v = TaggedVec::<usize, _>::from(vec![1, 2, 3]);
+    assert_eq!(v, [1, 2, 3]);
+    assert_eq!([1, 2, 3], v);
+    assert_eq!(v, vec![1, 2, 3]);
+    assert_eq!(vec![1, 2, 3], v);
+    assert_eq!(v, &[1, 2, 3][..]);
+    assert_eq!(&[1, 2, 3][..], v);
+    assert_ne!(v, [1, 2]);
+}
+
+#[test]
+fn next_and_prev_index_of() {
+    let v = TaggedVec::<usize, _>::from(vec!['a', 'b', 'c']);
+    assert_eq!(v.next_index_of(0), Some(1));
+    assert_eq!(v.next_index_of(2), None);
+    assert_eq!(v.prev_index_of(0), None);
+    assert_eq!(v.prev_index_of(2), Some(1));
+    assert_eq!(v.prev_index_of(5), None);
+}
+
+#[test]
+fn swap_with_and_replace_contents() {
+    let mut front = TaggedVec::<usize, _>::from(vec![1, 2, 3]);
+    let mut back = TaggedVec::<usize, _>::from(vec![4]);
+    front.swap_with(&mut back);
+    assert_eq!(front, [4]);
+    assert_eq!(back, [1, 2, 3]);
+
+    let capacity = back.capacity();
+    back.replace_contents([5, 6]);
+    assert_eq!(back, [5, 6]);
+    assert_eq!(back.capacity(), capacity);
+}
+
+#[test]
+fn splice_with_indices() {
+    let mut v = TaggedVec::<usize, _>::from(vec!['a', 'b', 'c', 'd']);
+    let (removed, inserted) = v.splice_with_indices(1..3, ['x', 'y', 'z']);
+    assert_eq!(removed.collect::<Vec<_>>(), [(1, 'b'), (2, 'c')]);
+    assert_eq!(inserted, TaggedRange::new(1, 4));
+    assert_eq!(v, ['a', 'x', 'y', 'z', 'd']);
+}
+
+#[test]
+fn truncate_and_drain_front() {
+    let mut v = TaggedVec::<usize, _>::from(vec![1, 2, 3, 4, 5]);
+    assert_eq!(v.drain_front(2).collect::<Vec<_>>(), [(0, 1), (1, 2)]);
+    assert_eq!(v, [3, 4, 5]);
+
+    v.truncate_front(1);
+    assert_eq!(v, [4, 5]);
+    v.truncate_front(10);
+    assert!(v.is_empty());
+}
+
+#[test]
+fn reserve_for_indices() {
+    let mut v = TaggedVec::<usize, u32>::from(vec![1, 2]);
+    v.reserve_for_indices([5, 100, 3]);
+    assert!(v.capacity() >= 101);
+    assert_eq!(v, [1, 2]);
+}
+
+#[test]
+#[should_panic = "capacity overflow"]
+fn reserve_for_max_index() {
+    let mut v = TaggedVec::<usize, u32>::new();
+    v.reserve_for_indices([usize::MAX]);
+}
+
+#[test]
+fn iter_indices_rev_and_step() {
+    let v = TaggedVec::<usize, _>::from(vec![0; 7]);
+    assert_eq!(
+        v.iter_indices_rev().collect::<Vec<_>>(),
+        [6, 5, 4, 3, 2, 1, 0]
+    );
+    assert_eq!(v.iter_indices_step(3).collect::<Vec<_>>(), [0, 3, 6]);
+    assert_eq!(v.iter_indices_step(3).rev().collect::<Vec<_>>(), [6, 3, 0]);
+    assert_eq!(v.iter_indices_step(10).collect::<Vec<_>>(), [0]);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn derive_tagged_index() {
+    use crate::TaggedIndex;
+
+    #[derive(TaggedIndex)]
+    #[tagged_index(arithmetic)]
+    struct NodeIndex(u32);
+
+    let mut v = TaggedVec::<NodeIndex, _>::from(vec!['a', 'b', 'c']);
+    let mut index = NodeIndex(0);
+    index += 2;
+    assert_eq!(index - 1, NodeIndex::from(1));
+    assert!(NodeIndex(1) < index);
+    assert_eq!(format!("{index:?}"), "NodeIndex(2)");
+    assert_eq!(usize::from(index), 2);
+
+    v[index] = 'C';
+    assert_eq!(
+        v.iter_indices(..).collect::<Vec<_>>(),
+        [NodeIndex(0), NodeIndex(1), NodeIndex(2)]
+    );
+    assert_eq!(v, ['a', 'b', 'C']);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn derive_tagged_index_constants() {
+    use crate::TaggedIndex;
+
+    #[derive(TaggedIndex)]
+    #[tagged_index(arithmetic, constants)]
+    struct EdgeIndex(u8);
+
+    const FIRST: EdgeIndex = EdgeIndex::ZERO.next();
+    assert_eq!(FIRST, EdgeIndex::new(1));
+    assert_eq!(EdgeIndex::MAX.get(), u8::MAX);
+    assert_eq!(EdgeIndex::MAX.checked_next(), None);
+    assert_eq!(EdgeIndex::new(254).checked_next(), Some(EdgeIndex::MAX));
+
+    let mut index = EdgeIndex::ZERO;
+    assert_eq!(index.increment(), EdgeIndex::ZERO);
+    assert_eq!(index.increment(), FIRST);
+    assert_eq!(index, FIRST + 1);
+}
+
+#[test]
+fn try_into_array() {
+    let v = TaggedVec::<usize, _>::from(vec![1, 2, 3]);
+    assert_eq!(v.clone().try_into_array(), Ok([1, 2, 3]));
+    assert_eq!(v.clone().try_into_array::<2>(), Err(v.clone()));
+
+    let (rest, array) = v.clone().try_split_off_array::<2>().unwrap();
+    assert_eq!(rest, [1]);
+    assert_eq!(array, [2, 3]);
+    assert_eq!(v.clone().try_split_off_array::<4>(), Err(v));
+}
+
+#[test]
+fn as_vec_and_from_vec_ref() {
+    let mut v = TaggedVec::<usize, _>::from(vec![1, 2]);
+    v.as_mut_vec().push(3);
+    assert_eq!(v.as_vec(), &vec![1, 2, 3]);
+
+    let mut untagged = vec![4, 5];
+    assert_eq!(TaggedVec::<usize, _>::from_vec_ref(&untagged)[1], 5);
+    TaggedVec::<usize, _>::from_vec_mut(&mut untagged).push(6);
+    assert_eq!(untagged, [4, 5, 6]);
+}
@@ -0,0 +1,170 @@
+//! Base64 and hex text encodings of the binary format of `TaggedVec`.
+//!
+//! The encoded bytes are the ones written by [`TaggedVec::write_binary_portable`], so the text can be decoded on any machine.
+
+use std::{
+    io::{Error, ErrorKind},
+    mem,
+};
+
+use crate::{
+    SwapBytes, TaggedVec,
+    portable_io::{HEADER_SIZE, LEN_OFFSET},
+};
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const HEX_ALPHABET: &[u8; 16] = b"0123456789abcdef";
+
+impl<Index, Value: SwapBytes> TaggedVec<Index, Value> {
+    /// Encodes the binary format of this `TaggedVec` as standard padded base64.
+    pub fn to_base64(&self) -> String {
+        let bytes = self.binary_bytes();
+        let mut result = String::with_capacity(bytes.len().div_ceil(3) * 4);
+        for chunk in bytes.chunks(3) {
+            let group = chunk
+                .iter()
+                .enumerate()
+                .fold(0u32, |group, (offset, byte)| {
+                    group | (u32::from(*byte) << (16 - 8 * offset))
+                });
+            for position in 0..4 {
+                if position <= chunk.len() {
+                    let sextet = (group >> (18 - 6 * position)) & 0x3f;
+                    result.push(char::from(BASE64_ALPHABET[sextet as usize]));
+                } else {
+                    result.push('=');
+                }
+            }
+        }
+        result
+    }
+
+    /// Decodes a `TaggedVec` from standard padded base64, as written by [`Self::to_base64`].
+    ///
+    /// Fails with [`ErrorKind::InvalidData`] if the text is not valid base64 or does not encode a `TaggedVec` of this value type.
+    pub fn from_base64(text: &str) -> std::io::Result<Self> {
+        let text = text.as_bytes();
+        if text.len() % 4 != 0 {
+            return Err(invalid_data("base64 length is not a multiple of four"));
+        }
+
+        let mut bytes = Vec::with_capacity(text.len() / 4 * 3);
+        for (chunk_index, chunk) in text.chunks(4).enumerate() {
+            let is_last = chunk_index == text.len() / 4 - 1;
+            let padding = chunk.iter().rev().take_while(|c| **c == b'=').count();
+            if padding > 2 || (padding > 0 && !is_last) {
+                return Err(invalid_data("invalid base64 padding"));
+            }
+
+            let mut group = 0u32;
+            for character in &chunk[..4 - padding] {
+                let sextet = BASE64_ALPHABET
+                    .iter()
+                    .position(|c| c == character)
+                    .ok_or_else(|| invalid_data("invalid base64 character"))?;
+                group = (group << 6) | sextet as u32;
+            }
+            group <<= 6 * padding;
+            bytes.extend_from_slice(&group.to_be_bytes()[1..4 - padding]);
+        }
+
+        Self::from_binary_bytes(&bytes)
+    }
+
+    /// Encodes the binary format of this `TaggedVec` as lowercase hex.
+    pub fn to_hex(&self) -> String {
+        let bytes = self.binary_bytes();
+        let mut result = String::with_capacity(bytes.len() * 2);
+        for byte in bytes {
+            result.push(char::from(HEX_ALPHABET[usize::from(byte >> 4)]));
+            result.push(char::from(HEX_ALPHABET[usize::from(byte & 0xf)]));
+        }
+        result
+    }
+
+    /// Decodes a `TaggedVec` from hex, as written by [`Self::to_hex`].
+    ///
+    /// Both lowercase and uppercase digits are accepted.
+    /// Fails with [`ErrorKind::InvalidData`] if the text is not valid hex or does not encode a `TaggedVec` of this value type.
+    pub fn from_hex(text: &str) -> std::io::Result<Self> {
+        let text = text.as_bytes();
+        if text.len() % 2 != 0 {
+            return Err(invalid_data("hex length is not a multiple of two"));
+        }
+
+        let bytes = text
+            .chunks(2)
+            .map(|pair| {
+                let digit = |character: u8| {
+                    char::from(character)
+                        .to_digit(16)
+                        .ok_or_else(|| invalid_data("invalid hex character"))
+                };
+                Ok(((digit(pair[0])? << 4) | digit(pair[1])?) as u8)
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+
+        Self::from_binary_bytes(&bytes)
+    }
+
+    fn binary_bytes(&self) -> Vec<u8> {
+        let mut bytes =
+            Vec::with_capacity(HEADER_SIZE + mem::size_of_val(self.as_untagged_slice()));
+        self.write_binary_portable(&mut bytes)
+            .expect("writing to a vector never fails");
+        bytes
+    }
+
+    fn from_binary_bytes(bytes: &[u8]) -> std::io::Result<Self> {
+        let len_bytes = bytes
+            .get(LEN_OFFSET..HEADER_SIZE)
+            .ok_or_else(|| invalid_data("missing header"))?;
+        let len = u64::from_le_bytes(len_bytes.try_into().unwrap());
+        if Some((bytes.len() - HEADER_SIZE) as u64)
+            != len.checked_mul(mem::size_of::<Value>() as u64)
+        {
+            return Err(invalid_data("data length does not match length header"));
+        }
+
+        Self::read_binary_portable(bytes)
+    }
+}
+
+fn invalid_data(message: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TaggedVec;
+
+    #[test]
+    fn text_roundtrip() {
+        for len in 0..4 {
+            let vec = TaggedVec::<usize, u8>::from((0..len).map(|i| i * 100).collect::<Vec<_>>());
+            assert_eq!(TaggedVec::from_base64(&vec.to_base64()).unwrap(), vec);
+            assert_eq!(TaggedVec::from_hex(&vec.to_hex()).unwrap(), vec);
+            assert_eq!(
+                TaggedVec::from_hex(&vec.to_hex().to_uppercase()).unwrap(),
+                vec
+            );
+        }
+
+        let vec = TaggedVec::<usize, u16>::from(vec![1, 2]);
+        assert!(TaggedVec::<usize, u16>::from_base64("not base64").is_err());
+        assert!(TaggedVec::<usize, u16>::from_hex("0g").is_err());
+        assert!(TaggedVec::<usize, u32>::from_hex(&vec.to_hex()).is_err());
+    }
+
+    #[test]
+    fn text_is_portable() {
+        let vec = TaggedVec::<usize, u16>::from(vec![0x0102]);
+        let hex = vec.to_hex();
+        assert_eq!(&hex[..20], "54414747454456430100");
+        assert_eq!(&hex[24..48], "020000000100000000000000");
+
+        let big_endian = "5441474745445643010001000200000001000000000000000102";
+        assert_eq!(TaggedVec::<usize, u16>::from_hex(big_endian).unwrap(), vec);
+    }
+}
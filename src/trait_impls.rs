@@ -1,6 +1,6 @@
 use std::{fmt::Debug, hash::Hash, marker::PhantomData};
 
-use crate::TaggedVec;
+use crate::{EntryIterator, EntryIteratorMut, TaggedVec};
 
 impl<Index, Value> Extend<Value> for TaggedVec<Index, Value> {
     fn extend<T: IntoIterator<Item = Value>>(&mut self, iter: T) {
@@ -8,6 +8,48 @@ impl<Index, Value> Extend<Value> for TaggedVec<Index, Value> {
     }
 }
 
+/// Appends entries whose indices must match the positions they are appended at.
+///
+/// Panics if an index differs from the current length of the `TaggedVec`.
+impl<Index: Into<usize>, Value> Extend<(Index, Value)> for TaggedVec<Index, Value> {
+    fn extend<T: IntoIterator<Item = (Index, Value)>>(&mut self, iter: T) {
+        let mut expected_index = self.vec.len();
+        self.vec.extend(iter.into_iter().map(|(index, value)| {
+            assert_eq!(
+                index.into(),
+                expected_index,
+                "extended with out-of-sequence index"
+            );
+            expected_index += 1;
+            value
+        }));
+    }
+}
+
+/// Iterates over the entries, i.e. pairs of indices and references to values.
+///
+/// Use [`TaggedVec::iter_values`] to iterate over the values only.
+impl<'a, Index: From<usize>, Value> IntoIterator for &'a TaggedVec<Index, Value> {
+    type Item = (Index, &'a Value);
+    type IntoIter = EntryIterator<'a, Index, Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        EntryIterator::new(&self.vec)
+    }
+}
+
+/// Iterates over the entries, i.e. pairs of indices and mutable references to values.
+///
+/// Use [`TaggedVec::iter_values_mut`] to iterate over the values only.
+impl<'a, Index: From<usize>, Value> IntoIterator for &'a mut TaggedVec<Index, Value> {
+    type Item = (Index, &'a mut Value);
+    type IntoIter = EntryIteratorMut<'a, Index, Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        EntryIteratorMut::new(&mut self.vec)
+    }
+}
+
 /////////////////////////////////////////
 ////// CONVERSIONS //////////////////////
 /////////////////////////////////////////
@@ -21,18 +63,35 @@ impl<Index, Value> FromIterator<Value> for TaggedVec<Index, Value> {
     }
 }
 
-impl<Index: From<usize> + Eq + Debug, Value> FromIterator<(Index, Value)>
-    for TaggedVec<Index, Value>
-{
+/// Collects entries in any order.
+///
+/// Panics if the indices do not form exactly the range `0..n`, i.e. if an index is missing or occurs more than once.
+impl<Index: Into<usize>, Value> FromIterator<(Index, Value)> for TaggedVec<Index, Value> {
     fn from_iter<T: IntoIterator<Item = (Index, Value)>>(iter: T) -> Self {
+        let mut entries: Vec<_> = iter
+            .into_iter()
+            .map(|(index, value)| (index.into(), value))
+            .collect();
+        // Stable sorting is linear on already sorted input.
+        entries.sort_by_key(|(index, _)| *index);
+
         Self {
             index_type: PhantomData,
-            vec: FromIterator::from_iter(iter.into_iter().enumerate().map(
-                |(expected_index, (actual_index, value))| {
-                    assert_eq!(Index::from(expected_index), actual_index);
+            vec: entries
+                .into_iter()
+                .enumerate()
+                .map(|(expected_index, (actual_index, value))| {
+                    assert!(
+                        actual_index >= expected_index,
+                        "duplicate index {actual_index}"
+                    );
+                    assert!(
+                        actual_index == expected_index,
+                        "missing index {expected_index}"
+                    );
                     value
-                },
-            )),
+                })
+                .collect(),
         }
     }
 }
@@ -88,6 +147,58 @@ impl<Index, Value: PartialEq> PartialEq for TaggedVec<Index, Value> {
 
 impl<Index, Value: Eq> Eq for TaggedVec<Index, Value> {}
 
+impl<Index, Value: PartialEq<Other>, Other> PartialEq<Vec<Other>> for TaggedVec<Index, Value> {
+    fn eq(&self, other: &Vec<Other>) -> bool {
+        self.vec == *other
+    }
+}
+
+impl<Index, Value: PartialEq<Other>, Other> PartialEq<[Other]> for TaggedVec<Index, Value> {
+    fn eq(&self, other: &[Other]) -> bool {
+        self.vec == other
+    }
+}
+
+impl<Index, Value: PartialEq<Other>, Other> PartialEq<&[Other]> for TaggedVec<Index, Value> {
+    fn eq(&self, other: &&[Other]) -> bool {
+        self.vec == *other
+    }
+}
+
+impl<Index, Value: PartialEq<Other>, Other, const N: usize> PartialEq<[Other; N]>
+    for TaggedVec<Index, Value>
+{
+    fn eq(&self, other: &[Other; N]) -> bool {
+        self.vec == other
+    }
+}
+
+impl<Index, Value, Other: PartialEq<Value>> PartialEq<TaggedVec<Index, Value>> for Vec<Other> {
+    fn eq(&self, other: &TaggedVec<Index, Value>) -> bool {
+        *self == other.vec
+    }
+}
+
+impl<Index, Value, Other: PartialEq<Value>> PartialEq<TaggedVec<Index, Value>> for [Other] {
+    fn eq(&self, other: &TaggedVec<Index, Value>) -> bool {
+        self == other.vec
+    }
+}
+
+impl<Index, Value, Other: PartialEq<Value>> PartialEq<TaggedVec<Index, Value>> for &[Other] {
+    fn eq(&self, other: &TaggedVec<Index, Value>) -> bool {
+        *self == other.vec
+    }
+}
+
+impl<Index, Value, Other: PartialEq<Value>, const N: usize> PartialEq<TaggedVec<Index, Value>>
+    for [Other; N]
+{
+    fn eq(&self, other: &TaggedVec<Index, Value>) -> bool {
+        *self == other.vec[..]
+    }
+}
+
 impl<Index, Value: PartialOrd> PartialOrd for TaggedVec<Index, Value> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         self.vec.partial_cmp(&other.vec)
@@ -0,0 +1,141 @@
+//! Translation tables between two index types.
+
+use crate::TaggedVec;
+
+/// A partial map from indices of type `FromIndex` to indices of type `ToIndex`.
+///
+/// Such maps are produced for example by compaction or sorting steps, which map each old index to a new index.
+pub struct IndexTranslation<FromIndex, ToIndex> {
+    map: TaggedVec<FromIndex, Option<ToIndex>>,
+}
+
+impl<FromIndex, ToIndex> IndexTranslation<FromIndex, ToIndex> {
+    /// Creates a translation that maps each index of `map` to its value.
+    pub fn new(map: TaggedVec<FromIndex, ToIndex>) -> Self {
+        Self {
+            map: map.vec.into_iter().map(Some).collect(),
+        }
+    }
+
+    /// Creates a translation that maps each index of `map` to its value, or to nothing if the value is `None`.
+    ///
+    /// This accepts the remaps returned for example by [`TaggedVec::retain_with_remap`].
+    pub fn from_partial(map: TaggedVec<FromIndex, Option<ToIndex>>) -> Self {
+        Self { map }
+    }
+
+    /// Returns the number of indices in the domain of the translation, including those that are mapped to nothing.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the domain of the translation is empty.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns the translation of the given index, or `None` if it is out of bounds or mapped to nothing.
+    pub fn translate(&self, index: FromIndex) -> Option<ToIndex>
+    where
+        FromIndex: Into<usize>,
+        ToIndex: Copy,
+    {
+        *self.map.vec.get(index.into())?
+    }
+
+    /// Returns the translation that first applies `self` and then `next`.
+    ///
+    /// An index is mapped to nothing if either translation maps it to nothing.
+    pub fn compose<NextIndex: Copy>(
+        &self,
+        next: &IndexTranslation<ToIndex, NextIndex>,
+    ) -> IndexTranslation<FromIndex, NextIndex>
+    where
+        ToIndex: Into<usize> + Copy,
+    {
+        IndexTranslation {
+            map: self
+                .map
+                .iter_values()
+                .map(|index| next.translate((*index)?))
+                .collect(),
+        }
+    }
+
+    /// Returns the inverse translation, which maps each index in the image of `self` back to its preimage.
+    ///
+    /// The domain of the inverse is `0..n`, where `n - 1` is the largest index in the image.
+    /// Panics if two indices are mapped to the same index.
+    pub fn invert(&self) -> IndexTranslation<ToIndex, FromIndex>
+    where
+        FromIndex: From<usize>,
+        ToIndex: Into<usize> + Copy,
+    {
+        let len = self
+            .map
+            .iter_values()
+            .flatten()
+            .map(|index| (*index).into() + 1)
+            .max()
+            .unwrap_or(0);
+        let mut inverse: Vec<Option<FromIndex>> = Vec::with_capacity(len);
+        inverse.resize_with(len, || None);
+
+        for (from_index, to_index) in self.map.iter_values().enumerate() {
+            if let Some(to_index) = to_index {
+                let to_index = (*to_index).into();
+                assert!(
+                    inverse[to_index].replace(from_index.into()).is_none(),
+                    "translation maps multiple indices to index {to_index}"
+                );
+            }
+        }
+
+        IndexTranslation {
+            map: inverse.into(),
+        }
+    }
+
+    /// Consumes the translation, returning the underlying map.
+    pub fn into_inner(self) -> TaggedVec<FromIndex, Option<ToIndex>> {
+        self.map
+    }
+}
+
+impl<FromIndex, ToIndex> From<TaggedVec<FromIndex, ToIndex>>
+    for IndexTranslation<FromIndex, ToIndex>
+{
+    fn from(map: TaggedVec<FromIndex, ToIndex>) -> Self {
+        Self::new(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IndexTranslation;
+    use crate::TaggedVec;
+
+    #[test]
+    fn translate_compose_invert() {
+        let mut vec = TaggedVec::<usize, _>::from(vec![1, 2, 3, 4]);
+        let compacted =
+            IndexTranslation::from_partial(vec.retain_with_remap(|value| value % 2 == 0));
+        let reversed = IndexTranslation::<usize, u8>::new(vec![1, 0].into());
+
+        assert_eq!(compacted.translate(1), Some(0));
+        assert_eq!(compacted.translate(2), None);
+        assert_eq!(compacted.translate(4), None);
+
+        let composed = compacted.compose(&reversed);
+        assert_eq!(composed.into_inner(), [None, Some(1), None, Some(0)]);
+
+        let inverse = compacted.invert();
+        assert_eq!(inverse.into_inner(), [Some(1), Some(3)]);
+    }
+
+    #[test]
+    #[should_panic = "translation maps multiple indices to index 0"]
+    fn invert_not_injective() {
+        IndexTranslation::<usize, usize>::new(vec![0, 0].into()).invert();
+    }
+}
@@ -0,0 +1,222 @@
+//! Borrowed read-only views into a `TaggedVec`, including lazily mapped views.
+
+use std::{marker::PhantomData, ops::RangeBounds};
+
+use crate::{TaggedVec, mapped_range_bounds::MappedRangeBounds};
+
+/// A read-only view of a contiguous range of a [`TaggedVec`] that keeps the original indices.
+pub struct TaggedVecView<'a, Index, Value> {
+    index_type: PhantomData<Index>,
+    /// The index of the first value of the view in the original vector.
+    offset: usize,
+    values: &'a [Value],
+}
+
+/// A view of a `TaggedVec` that lazily computes a function of each value on access, created by [`TaggedVecView::map`].
+pub struct MappedTaggedVecView<'a, Index, Value, F> {
+    view: TaggedVecView<'a, Index, Value>,
+    f: F,
+}
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Returns a view of the whole vector.
+    pub fn view(&self) -> TaggedVecView<'_, Index, Value> {
+        TaggedVecView {
+            index_type: PhantomData,
+            offset: 0,
+            values: &self.vec,
+        }
+    }
+
+    /// Returns a view of the given range of the vector.
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn view_range(&self, range: impl RangeBounds<Index>) -> TaggedVecView<'_, Index, Value>
+    where
+        Index: Copy,
+        usize: From<Index>,
+    {
+        let range = MappedRangeBounds::new(range).to_range(self.len());
+        TaggedVecView {
+            index_type: PhantomData,
+            offset: range.start,
+            values: &self.vec[range],
+        }
+    }
+
+    /// Returns a view of the last `n` values of the vector, or of the whole vector if it has less than `n` values.
+    pub fn last_n(&self, n: usize) -> TaggedVecView<'_, Index, Value> {
+        let offset = self.len().saturating_sub(n);
+        TaggedVecView {
+            index_type: PhantomData,
+            offset,
+            values: &self.vec[offset..],
+        }
+    }
+}
+
+impl<'a, Index, Value> TaggedVecView<'a, Index, Value> {
+    /// Returns the number of values in the view.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the view contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the index of the first value of the view.
+    pub fn start_index(&self) -> Index
+    where
+        Index: From<usize>,
+    {
+        self.offset.into()
+    }
+
+    /// Returns the values of the view as an untagged slice.
+    pub fn as_untagged_slice(&self) -> &'a [Value] {
+        self.values
+    }
+
+    /// Returns a reference to the value at the given index, or `None` if the index is outside of the view.
+    pub fn get(&self, index: Index) -> Option<&'a Value>
+    where
+        Index: Into<usize>,
+    {
+        self.values.get(index.into().checked_sub(self.offset)?)
+    }
+
+    /// Returns an iterator over the entries of the view in order of their indices.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (Index, &'a Value)> + ExactSizeIterator
+    where
+        Index: From<usize>,
+    {
+        let offset = self.offset;
+        self.values
+            .iter()
+            .enumerate()
+            .map(move |(index, value)| ((offset + index).into(), value))
+    }
+
+    /// Returns a view that lazily applies `f` to each value on access.
+    pub fn map<Output, F: Fn(&Value) -> Output>(
+        self,
+        f: F,
+    ) -> MappedTaggedVecView<'a, Index, Value, F> {
+        MappedTaggedVecView { view: self, f }
+    }
+}
+
+impl<'a, Index, Value, Output, F: Fn(&Value) -> Output> MappedTaggedVecView<'a, Index, Value, F> {
+    /// Returns the number of values in the view.
+    pub fn len(&self) -> usize {
+        self.view.len()
+    }
+
+    /// Returns `true` if the view contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.view.is_empty()
+    }
+
+    /// Computes the mapped value at the given index, or returns `None` if the index is outside of the view.
+    pub fn get(&self, index: Index) -> Option<Output>
+    where
+        Index: Into<usize>,
+    {
+        self.view.get(index).map(&self.f)
+    }
+
+    /// Returns an iterator over the mapped entries of the view in order of their indices.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (Index, Output)> + ExactSizeIterator + '_
+    where
+        Index: From<usize>,
+    {
+        self.view
+            .iter()
+            .map(|(index, value)| (index, (self.f)(value)))
+    }
+
+    /// Returns an iterator over the mapped entries in the given range of indices.
+    ///
+    /// Panics if the range is not within the view.
+    pub fn iter_range(
+        &self,
+        range: impl RangeBounds<Index>,
+    ) -> impl DoubleEndedIterator<Item = (Index, Output)> + ExactSizeIterator + '_
+    where
+        Index: From<usize> + Copy,
+        usize: From<Index>,
+    {
+        let offset = self.view.offset;
+        let range = MappedRangeBounds::new(range).to_range(offset + self.len());
+        assert!(
+            range.start >= offset,
+            "range start {} is outside of the view",
+            range.start
+        );
+        self.view.values[range.start - offset..range.end - offset]
+            .iter()
+            .zip(range)
+            .map(|(value, index)| (index.into(), (self.f)(value)))
+    }
+
+    /// Returns a view that lazily applies `g` to the result of the current mapping.
+    pub fn map<NewOutput>(
+        self,
+        g: impl Fn(Output) -> NewOutput,
+    ) -> MappedTaggedVecView<'a, Index, Value, impl Fn(&Value) -> NewOutput> {
+        let f = self.f;
+        MappedTaggedVecView {
+            view: self.view,
+            f: move |value: &Value| g(f(value)),
+        }
+    }
+}
+
+impl<Index, Value> Clone for TaggedVecView<'_, Index, Value> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Index, Value> Copy for TaggedVecView<'_, Index, Value> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::TaggedVec;
+
+    #[test]
+    fn mapped_view() {
+        let vec = TaggedVec::<usize, _>::from(vec![1, 2, 3, 4]);
+        let view = vec.view_range(1..);
+        assert_eq!(view.get(0), None);
+        assert_eq!(view.get(1), Some(&2));
+
+        let squares = view.map(|value| value * value);
+        assert_eq!(squares.get(3), Some(16));
+        assert_eq!(
+            squares.iter().collect::<Vec<_>>(),
+            [(1, 4), (2, 9), (3, 16)]
+        );
+        assert_eq!(squares.iter_range(2..=2).collect::<Vec<_>>(), [(2, 9)]);
+
+        let strings = squares.map(|square| square.to_string());
+        assert_eq!(strings.get(2).as_deref(), Some("9"));
+        assert_eq!(vec.view().map(|value| value % 2 == 0).len(), 4);
+    }
+
+    #[test]
+    fn last_n() {
+        let vec = TaggedVec::<usize, _>::from(vec!['a', 'b', 'c']);
+        assert_eq!(
+            vec.last_n(2).iter().collect::<Vec<_>>(),
+            [(1, &'b'), (2, &'c')]
+        );
+        assert_eq!(vec.last_n(5).len(), 3);
+        assert_eq!(
+            vec.iter_rev().collect::<Vec<_>>(),
+            [(2, &'c'), (1, &'b'), (0, &'a')]
+        );
+    }
+}
@@ -0,0 +1,88 @@
+//! Mutable access to overlapping windows of a `TaggedVec`.
+
+use std::marker::PhantomData;
+
+use crate::TaggedVec;
+
+/// A lending iterator over overlapping mutable windows of a [`TaggedVec`], created by [`TaggedVec::windows_mut`].
+///
+/// Since each window borrows the underlying vector mutably, this does not implement [`Iterator`].
+/// Instead, windows are obtained with [`Self::next`] and must be dropped before requesting the next one:
+///
+/// ```
+/// # use tagged_vec::TaggedVec;
+/// let mut vec = TaggedVec::<usize, i32>::from(vec![1, 2, 3, 4]);
+/// let mut windows = vec.windows_mut(2);
+/// while let Some((_, window)) = windows.next() {
+///     window[1] += window[0];
+/// }
+/// assert_eq!(vec.as_untagged_slice(), &[1, 3, 6, 10]);
+/// ```
+pub struct WindowsMut<'a, Index, Value> {
+    slice: &'a mut [Value],
+    window_len: usize,
+    position: usize,
+    index_type: PhantomData<Index>,
+}
+
+impl<Index, Value> TaggedVec<Index, Value> {
+    /// Returns a lending iterator over all overlapping mutable windows of length `window_len`, see [`WindowsMut`].
+    ///
+    /// Panics if `window_len` is zero.
+    pub fn windows_mut(&mut self, window_len: usize) -> WindowsMut<'_, Index, Value> {
+        assert!(window_len > 0, "window length must be non-zero");
+        WindowsMut {
+            slice: &mut self.vec,
+            window_len,
+            position: 0,
+            index_type: PhantomData,
+        }
+    }
+
+    /// Calls `f` for all overlapping mutable windows of length `window_len` in order, together with the index of the first value of the window.
+    ///
+    /// Panics if `window_len` is zero.
+    pub fn for_each_window_mut(&mut self, window_len: usize, mut f: impl FnMut(Index, &mut [Value]))
+    where
+        Index: From<usize>,
+    {
+        let mut windows = self.windows_mut(window_len);
+        while let Some((index, window)) = windows.next() {
+            f(index, window);
+        }
+    }
+}
+
+impl<Index, Value> WindowsMut<'_, Index, Value> {
+    /// Returns the next window together with the index of its first value, or `None` if there are no more windows.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<(Index, &mut [Value])>
+    where
+        Index: From<usize>,
+    {
+        let end = self.position.checked_add(self.window_len)?;
+        let window = self.slice.get_mut(self.position..end)?;
+        let index = self.position.into();
+        self.position += 1;
+        Some((index, window))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TaggedVec;
+
+    #[test]
+    fn for_each_window_mut() {
+        let mut vec = TaggedVec::<usize, _>::from(vec![1, 2, 3, 4]);
+        let mut indices = Vec::new();
+        vec.for_each_window_mut(3, |index, window| {
+            indices.push(index);
+            window[2] = window[0] + window[1];
+        });
+        assert_eq!(indices, [0, 1]);
+        assert_eq!(vec, [1, 2, 3, 5]);
+
+        vec.for_each_window_mut(5, |_, _| panic!("window longer than vector"));
+    }
+}
@@ -0,0 +1,72 @@
+//! Joint iteration over multiple `TaggedVec`s sharing the same index type.
+
+use crate::TaggedVec;
+
+impl<Index, A> TaggedVec<Index, A> {
+    /// Returns an iterator over the entries of `self`, `b` and `c` at the same indices.
+    ///
+    /// Panics if the vectors have different lengths.
+    pub fn zip3<'a, B, C>(
+        &'a self,
+        b: &'a TaggedVec<Index, B>,
+        c: &'a TaggedVec<Index, C>,
+    ) -> impl DoubleEndedIterator<Item = (Index, &'a A, &'a B, &'a C)> + ExactSizeIterator
+    where
+        Index: From<usize>,
+    {
+        assert_eq!(self.len(), b.len(), "zipped vectors have different lengths");
+        assert_eq!(self.len(), c.len(), "zipped vectors have different lengths");
+
+        self.vec
+            .iter()
+            .zip(&b.vec)
+            .zip(&c.vec)
+            .enumerate()
+            .map(|(index, ((a, b), c))| (index.into(), a, b, c))
+    }
+
+    /// Returns an iterator over the entries of `self`, `b`, `c` and `d` at the same indices.
+    ///
+    /// Panics if the vectors have different lengths.
+    pub fn zip4<'a, B, C, D>(
+        &'a self,
+        b: &'a TaggedVec<Index, B>,
+        c: &'a TaggedVec<Index, C>,
+        d: &'a TaggedVec<Index, D>,
+    ) -> impl DoubleEndedIterator<Item = (Index, &'a A, &'a B, &'a C, &'a D)> + ExactSizeIterator
+    where
+        Index: From<usize>,
+    {
+        assert_eq!(self.len(), d.len(), "zipped vectors have different lengths");
+
+        self.zip3(b, c)
+            .zip(&d.vec)
+            .map(|((index, a, b, c), d)| (index, a, b, c, d))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TaggedVec;
+
+    #[test]
+    fn zip4() {
+        let a = TaggedVec::<usize, _>::from(vec![1, 2]);
+        let b = TaggedVec::from(vec!['a', 'b']);
+        let c = TaggedVec::from(vec!["x", "y"]);
+        let d = TaggedVec::from(vec![true, false]);
+
+        assert_eq!(
+            a.zip4(&b, &c, &d).rev().collect::<Vec<_>>(),
+            [(1, &2, &'b', &"y", &false), (0, &1, &'a', &"x", &true)]
+        );
+    }
+
+    #[test]
+    #[should_panic = "zipped vectors have different lengths"]
+    fn zip3_length_mismatch() {
+        let a = TaggedVec::<usize, _>::from(vec![1, 2]);
+        let b = TaggedVec::from(vec!['a']);
+        let _ = a.zip3(&b, &a);
+    }
+}
@@ -0,0 +1,200 @@
+//! Derive macros for index types of [tagged-vec](https://crates.io/crates/tagged-vec).
+//!
+//! Use them via the `derive` feature of `tagged-vec` instead of depending on this crate directly.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, parse_macro_input};
+
+/// Derives the traits needed to use a single-field tuple struct as index of a `TaggedVec`.
+///
+/// Generates `From<usize>` for the struct and `From<Struct>` for `usize`, as well as `Clone`, `Copy`, `Debug`, `PartialEq`, `Eq`, `PartialOrd`, `Ord` and `Hash`.
+/// The field may be of any integer type that converts from and into `usize` via `TryFrom`, and the conversions panic if the value does not fit.
+///
+/// With the attribute `#[tagged_index(arithmetic)]`, additionally generates `Add<usize>`, `Sub<usize>`, `AddAssign<usize>` and `SubAssign<usize>`.
+///
+/// With the attribute `#[tagged_index(constants)]`, additionally generates the associated constants `ZERO` and `MAX`, the const functions `new` and `get` that convert from and into the field type, as well as `next`, `checked_next` and `increment` to step to the following index.
+/// Multiple options can be combined as in `#[tagged_index(arithmetic, constants)]`.
+#[proc_macro_derive(TaggedIndex, attributes(tagged_index))]
+pub fn derive_tagged_index(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match tagged_index(input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn tagged_index(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let error = || {
+        syn::Error::new(
+            input.ident.span(),
+            "TaggedIndex can only be derived for tuple structs with a single field",
+        )
+    };
+    let field = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+            _ => return Err(error()),
+        },
+        _ => return Err(error()),
+    };
+    let field_type = &field.ty;
+
+    let mut arithmetic = false;
+    let mut constants = false;
+    for attribute in &input.attrs {
+        if attribute.path().is_ident("tagged_index") {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("arithmetic") {
+                    arithmetic = true;
+                    Ok(())
+                } else if meta.path.is_ident("constants") {
+                    constants = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown tagged_index attribute"))
+                }
+            })?;
+        }
+    }
+
+    let mut tokens = quote! {
+        impl #impl_generics ::core::convert::From<usize> for #name #type_generics #where_clause {
+            fn from(value: usize) -> Self {
+                Self(::core::convert::TryFrom::try_from(value).expect("index does not fit into the index type"))
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#name #type_generics> for usize #where_clause {
+            fn from(value: #name #type_generics) -> Self {
+                ::core::convert::TryFrom::try_from(value.0).expect("index does not fit into usize")
+            }
+        }
+
+        impl #impl_generics ::core::clone::Clone for #name #type_generics #where_clause {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl #impl_generics ::core::marker::Copy for #name #type_generics #where_clause {}
+
+        impl #impl_generics ::core::fmt::Debug for #name #type_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_tuple(::core::stringify!(#name)).field(&self.0).finish()
+            }
+        }
+
+        impl #impl_generics ::core::cmp::PartialEq for #name #type_generics #where_clause {
+            fn eq(&self, other: &Self) -> bool {
+                <#field_type as ::core::cmp::PartialEq>::eq(&self.0, &other.0)
+            }
+        }
+
+        impl #impl_generics ::core::cmp::Eq for #name #type_generics #where_clause {}
+
+        impl #impl_generics ::core::cmp::PartialOrd for #name #type_generics #where_clause {
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        impl #impl_generics ::core::cmp::Ord for #name #type_generics #where_clause {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                <#field_type as ::core::cmp::Ord>::cmp(&self.0, &other.0)
+            }
+        }
+
+        impl #impl_generics ::core::hash::Hash for #name #type_generics #where_clause {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                <#field_type as ::core::hash::Hash>::hash(&self.0, state);
+            }
+        }
+    };
+
+    if arithmetic {
+        tokens.extend(quote! {
+            impl #impl_generics ::core::ops::Add<usize> for #name #type_generics #where_clause {
+                type Output = Self;
+
+                fn add(self, rhs: usize) -> Self {
+                    Self::from(usize::from(self).checked_add(rhs).expect("index overflow"))
+                }
+            }
+
+            impl #impl_generics ::core::ops::Sub<usize> for #name #type_generics #where_clause {
+                type Output = Self;
+
+                fn sub(self, rhs: usize) -> Self {
+                    Self::from(usize::from(self).checked_sub(rhs).expect("index underflow"))
+                }
+            }
+
+            impl #impl_generics ::core::ops::AddAssign<usize> for #name #type_generics #where_clause {
+                fn add_assign(&mut self, rhs: usize) {
+                    *self = *self + rhs;
+                }
+            }
+
+            impl #impl_generics ::core::ops::SubAssign<usize> for #name #type_generics #where_clause {
+                fn sub_assign(&mut self, rhs: usize) {
+                    *self = *self - rhs;
+                }
+            }
+        });
+    }
+
+    if constants {
+        tokens.extend(quote! {
+            impl #impl_generics #name #type_generics #where_clause {
+                /// The smallest index.
+                pub const ZERO: Self = Self(0);
+
+                /// The largest index that the field type can represent.
+                pub const MAX: Self = Self(<#field_type>::MAX);
+
+                /// Creates an index from the value of its field.
+                pub const fn new(value: #field_type) -> Self {
+                    Self(value)
+                }
+
+                /// Returns the value of the field of this index.
+                pub const fn get(self) -> #field_type {
+                    self.0
+                }
+
+                /// Returns the following index, or `None` if this is [`Self::MAX`].
+                pub const fn checked_next(self) -> ::core::option::Option<Self> {
+                    match self.0.checked_add(1) {
+                        ::core::option::Option::Some(value) => ::core::option::Option::Some(Self(value)),
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    }
+                }
+
+                /// Returns the following index.
+                ///
+                /// Panics if this is [`Self::MAX`].
+                pub const fn next(self) -> Self {
+                    match self.checked_next() {
+                        ::core::option::Option::Some(next) => next,
+                        ::core::option::Option::None => ::core::panic!("index overflow"),
+                    }
+                }
+
+                /// Advances this index to the following index and returns its previous value.
+                ///
+                /// Panics if this is [`Self::MAX`].
+                pub fn increment(&mut self) -> Self {
+                    let previous = *self;
+                    *self = self.next();
+                    previous
+                }
+            }
+        });
+    }
+
+    Ok(tokens)
+}